@@ -0,0 +1,41 @@
+// `cargo bench` target using `criterion`. Like `fuzz/fuzz_targets/`, there's no `Cargo.toml`
+// anywhere in this tree to register `criterion` as a dev-dependency or declare a `[[bench]]`
+// entry, so this can't actually be run with `cargo bench` here; it's written as though the
+// manifest existed, to demonstrate the speedup `symbol::intern` gives over raw
+// `Symbol::from_str` in a tight loop.
+
+use {
+    criterion::{Criterion, black_box, criterion_group, criterion_main},
+    garguile::{intern, symbol::Symbol, with_guile},
+};
+
+const NAMES: &[&str] = &["foo", "bar", "baz", "quux", "define", "lambda", "let*"];
+
+fn from_str(c: &mut Criterion) {
+    with_guile(|guile| {
+        c.bench_function("Symbol::from_str, repeated", |b| {
+            b.iter(|| {
+                for name in NAMES {
+                    black_box(Symbol::from_str(name, guile));
+                }
+            });
+        });
+    })
+    .unwrap();
+}
+
+fn interned(c: &mut Criterion) {
+    with_guile(|guile| {
+        c.bench_function("symbol::intern, repeated", |b| {
+            b.iter(|| {
+                for name in NAMES {
+                    black_box(intern!(guile, name));
+                }
+            });
+        });
+    })
+    .unwrap();
+}
+
+criterion_group!(benches, from_str, interned);
+criterion_main!(benches);
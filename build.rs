@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use std::{
+    env,
     error::Error,
     ffi::OsStr,
     fmt::{self, Display, Formatter},
@@ -22,27 +23,87 @@ use std::{
 };
 
 // must be dynamically linked for lgpl
-const PKG_CONFIG_ARGS: &[&str] = &["--cflags", "--libs", "--shared", "guile-3.0"];
-
-pub fn pkg_config_guile() -> Result<Vec<u8>, PkgConfigError> {
-    Command::new("pkg-config")
-        .args(PKG_CONFIG_ARGS)
-        .output()
-        .map(|output| output.stdout)
-        .map_err(PkgConfigError)
+const PKG_CONFIG_BASE_ARGS: &[&str] = &["--cflags", "--libs", "--shared"];
+// Tried in this order unless `GARGUILE_GUILE_PKG` or the `guile-2.2`/`guile-3.0` features narrow
+// the list down to the requested version(s).
+const DEFAULT_PKG_CANDIDATES: &[&str] = &["guile-3.0", "guile-2.2"];
+
+/// Which `.pc` module names to try, in priority order.
+///
+/// `GARGUILE_GUILE_PKG` wins outright; otherwise the `guile-2.2`/`guile-3.0` cargo features (if
+/// either is set) narrow [DEFAULT_PKG_CANDIDATES] down to just the requested version(s).
+fn pkg_candidates() -> Vec<String> {
+    if let Ok(pkg) = env::var("GARGUILE_GUILE_PKG") {
+        return vec![pkg];
+    }
+
+    let mut candidates: Vec<String> = DEFAULT_PKG_CANDIDATES
+        .iter()
+        .copied()
+        .filter(|module| {
+            let feature = format!(
+                "CARGO_FEATURE_{}",
+                module.to_uppercase().replace(['-', '.'], "_")
+            );
+            env::var_os(feature).is_some()
+        })
+        .map(str::to_owned)
+        .collect();
+
+    if candidates.is_empty() {
+        candidates.extend(
+            DEFAULT_PKG_CANDIDATES
+                .iter()
+                .map(|&module| module.to_owned()),
+        );
+    }
+
+    candidates
+}
+
+/// Try each of [pkg_candidates] against `pkg-config` in order, returning the first module name
+/// that resolves along with its `--cflags --libs --shared` output.
+pub fn pkg_config_guile() -> Result<(String, Vec<u8>), PkgConfigError> {
+    let mut attempted = Vec::new();
+    for module in pkg_candidates() {
+        let output = Command::new("pkg-config")
+            .args(PKG_CONFIG_BASE_ARGS)
+            .arg(&module)
+            .output()
+            .map_err(PkgConfigError::Exec)?;
+
+        if output.status.success() {
+            return Ok((module, output.stdout));
+        }
+        attempted.push(module);
+    }
+
+    Err(PkgConfigError::NoneResolved(attempted))
 }
 
 #[derive(Debug)]
-pub struct PkgConfigError(io::Error);
+pub enum PkgConfigError {
+    Exec(io::Error),
+    NoneResolved(Vec<String>),
+}
 impl Display for PkgConfigError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
-        write!(f, "failed to execute `pkg-config")
-            .and_then(|_| {
-                PKG_CONFIG_ARGS
-                    .iter()
-                    .try_for_each(|arg| write!(f, " {arg}"))
-            })
-            .and_then(|_| write!(f, "`: {}", self.0))
+        match self {
+            Self::Exec(err) => write!(f, "failed to execute `pkg-config`: {err}"),
+            Self::NoneResolved(attempted) => {
+                write!(f, "no Guile `.pc` module found; tried: ")?;
+                let mut attempted = attempted.iter();
+                if let Some(first) = attempted.next() {
+                    write!(f, "{first}")?;
+                }
+                attempted.try_for_each(|module| write!(f, ", {module}"))?;
+                write!(
+                    f,
+                    " (override with the `GARGUILE_GUILE_PKG` env var, or the `guile-2.2`/\
+                     `guile-3.0` features)"
+                )
+            }
+        }
     }
 }
 impl Error for PkgConfigError {}
@@ -64,8 +125,15 @@ cargo:rerun-if-changed=src/reexports.c\n",
         )
         .unwrap_or_else(die);
 
-    pkg_config_guile()
-        .unwrap_or_else(die)
+    let (module, flags) = pkg_config_guile().unwrap_or_else(die);
+    // e.g. "guile-3.0" -> "guile_3_0", so `sys` can `#[cfg(guile_3_0)]` APIs missing on older
+    // Guile.
+    let cfg = module.replace(['-', '.'], "_");
+    stdout
+        .write_all(format!("cargo:rustc-cfg={cfg}\n").as_bytes())
+        .unwrap_or_else(die);
+
+    flags
         .split(u8::is_ascii_whitespace)
         .filter(|arg| !arg.is_empty())
         .try_fold(cc::Build::new(), |mut build, arg| {
@@ -89,4 +157,12 @@ cargo:rerun-if-changed=src/reexports.c\n",
         .unwrap_or_else(die)
         .file("src/reexports.c")
         .compile("reexports");
+
+    if env::var_os("CARGO_FEATURE_VALGRIND").is_some() {
+        let mut stdout = stdout().lock();
+        stdout
+            .write_all(b"cargo:rerun-if-changed=src/valgrind.c\n")
+            .unwrap_or_else(die);
+        cc::Build::new().file("src/valgrind.c").compile("valgrind");
+    }
 }
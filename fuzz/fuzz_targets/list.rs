@@ -0,0 +1,34 @@
+#![no_main]
+
+// See `tuple.rs` for why this can't be wired up to an actual `cargo fuzz run` in this tree.
+//
+// `List<'gm, T>` is indexed by the session lifetime, so it can't go through the generic
+// `roundtrip` helper (see `src/fuzz.rs`); this drives `with_guile` directly instead.
+
+use {
+    arbitrary::{Arbitrary, Unstructured},
+    garguile::{
+        collections::list::List,
+        scm::{ToScm, TryFromScm},
+        with_guile,
+    },
+    libfuzzer_sys::fuzz_target,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let (Ok(to_send), Ok(expected)) = (
+        std::vec::Vec::<i32>::arbitrary_take_rest(Unstructured::new(data)),
+        std::vec::Vec::<i32>::arbitrary_take_rest(Unstructured::new(data)),
+    ) else {
+        return;
+    };
+
+    with_guile(|guile| {
+        let scm = List::from_iter_ordered(to_send, guile).to_scm(guile);
+        let recovered = List::<i32>::try_from_scm(scm, guile).expect("list should round-trip");
+        assert_eq!(
+            recovered.into_iter().collect::<std::vec::Vec<_>>(),
+            expected
+        );
+    });
+});
@@ -0,0 +1,32 @@
+#![no_main]
+
+// See `tuple.rs` for why this can't be wired up to an actual `cargo fuzz run` in this tree.
+//
+// `garguile::string::String<'gm>` is indexed by the session lifetime, so it can't go through the
+// generic `roundtrip` helper (see `src/fuzz.rs`); this drives `with_guile` directly instead.
+
+use {
+    arbitrary::{Arbitrary, Unstructured},
+    garguile::{
+        scm::{ToScm, TryFromScm},
+        string::String as GuileString,
+        with_guile,
+    },
+    libfuzzer_sys::fuzz_target,
+    std::ops::Deref,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let (Ok(to_send), Ok(expected)) = (
+        std::string::String::arbitrary_take_rest(Unstructured::new(data)),
+        std::string::String::arbitrary_take_rest(Unstructured::new(data)),
+    ) else {
+        return;
+    };
+
+    with_guile(|guile| {
+        let scm = GuileString::from_str(&to_send, guile).to_scm(guile);
+        let recovered = GuileString::try_from_scm(scm, guile).expect("string should round-trip");
+        assert_eq!(recovered.as_string().deref(), expected);
+    });
+});
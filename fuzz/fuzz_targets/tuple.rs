@@ -0,0 +1,17 @@
+#![no_main]
+
+// `cargo fuzz` target: no `fuzz/Cargo.toml` exists in this tree (there's no `Cargo.toml` anywhere
+// in it to depend on in the first place — see `src/fuzz.rs`), so this can't actually be run with
+// `cargo fuzz run tuple` here. It's written as though the manifest existed.
+//
+// `(u8, (i32, String))`, as suggested in the originating request, doesn't fit `roundtrip`'s generic
+// bound: `garguile::string::String<'gm>` is indexed by the session lifetime, same as
+// `List<'gm, _>`, so it can't implement `for<'gm> ToScm<'gm>` as a bare type parameter. This target
+// sticks to lifetime-free nested tuples; `string.rs` covers `String` with its own harness.
+
+use {garguile::fuzz::roundtrip, libfuzzer_sys::fuzz_target};
+
+fuzz_target!(|data: &[u8]| {
+    roundtrip::<(u8, (i32, u8))>(data);
+    roundtrip::<(u8, u16, u32, u64, i8, i16, i32, i64)>(data);
+});
@@ -22,12 +22,14 @@ use {
 };
 
 pub enum Rest {
-    /// Keyworded arguments so that you can call it with `:arg val`.
-    Keyword(Vec<(String, Box<Type>)>),
+    /// Keyworded arguments so that you can call it with `:arg val`, alongside the expression from
+    /// each one's `#[default = expr]` attribute, if any; see [keyword_default].
+    Keyword(Vec<(String, Box<Type>, Option<Expr>)>),
     /// Represents the optional variadic arguments.
     ///
     /// This would be the `r` in `(lambda (. r) r)`
-    // the list type may be useful one day
+    // the declared type is only used to distinguish this from `Keyword` here; the trampoline
+    // relies on type inference from the call to the wrapped function instead of reading it back
     #[expect(dead_code)]
     List(Box<Type>),
 }
@@ -35,9 +37,52 @@ pub enum Rest {
 pub struct FnArgs {
     pub guile: bool,
     pub required: Vec<Type>,
-    pub optional: Vec<Type>,
+    /// Each optional argument's declared type, alongside the expression from its
+    /// `#[optional = expr]`/`#[default = expr]` attribute, if any; see [optional_default].
+    pub optional: Vec<(Type, Option<Expr>)>,
     pub rest: Option<Rest>,
 }
+
+/// Read the default-value expression off `attrs` from whichever of `idents` appears last, if any.
+fn default_value(attrs: &[Attribute], idents: &[&str]) -> Result<Option<Expr>, syn::Error> {
+    attrs
+        .iter()
+        .rfind(|attr| idents.iter().any(|ident| attr.path().is_ident(ident)))
+        .and_then(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue { value, .. }) => Some(Ok(value.clone())),
+            Meta::Path(_) => None,
+            meta => Some(Err(syn::Error::new(
+                meta.span(),
+                format!(
+                    "expected {}",
+                    idents
+                        .iter()
+                        .map(|ident| format!("`#[{ident} = expr]`"))
+                        .collect::<Vec<_>>()
+                        .join(" or ")
+                ),
+            ))),
+        })
+        .transpose()
+}
+
+/// Read the default-value expression off an `#[optional]` argument's attributes, if it has one.
+///
+/// Accepts either `#[optional = expr]` or `#[default = expr]`; a bare `#[optional]` (or no
+/// attribute at all, for an argument that's optional only because an earlier sibling switched the
+/// parser into its optional state) means there's no default.
+fn optional_default(attrs: &[Attribute]) -> Result<Option<Expr>, syn::Error> {
+    default_value(attrs, &["optional", "default"])
+}
+
+/// Read the default-value expression off a `#[keyword]` argument's attributes, if it has one.
+///
+/// Accepts `#[default = expr]` (`#[keyword]` itself is reserved for naming the keyword, see
+/// [the `Rest::Keyword` fold below][TryFrom::try_from]); no attribute means the argument is bound
+/// as `Option<T>` instead, same as a bare `#[optional]`.
+fn keyword_default(attrs: &[Attribute]) -> Result<Option<Expr>, syn::Error> {
+    default_value(attrs, &["default"])
+}
 impl TryFrom<ItemFn> for FnArgs {
     type Error = syn::Error;
 
@@ -95,7 +140,17 @@ impl TryFrom<ItemFn> for FnArgs {
                     .unwrap_or_default()
             })
             .is_some();
-        args.map(|arg| arg
+        // Every argument is visited regardless of whether an earlier one errored, so a single
+        // expansion reports every malformed argument at once instead of stopping at the first;
+        // see `syn::Error::combine` below.
+        let mut errors: Option<syn::Error> = None;
+        let mut push_error = |error: syn::Error| match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        };
+
+        let (required, optional, rest) = args
+            .map(|arg| arg
                 .map(|arg| {
                     let PatType { ref attrs, .. } = arg;
                     if let Some(next_attrs) = state.next_attrs() {
@@ -109,29 +164,41 @@ impl TryFrom<ItemFn> for FnArgs {
                     }
                     (state, arg)
                 }))
-            .try_fold(
+            .fold(
                 (Vec::new(), Vec::new(), None),
                 |(mut required, mut optional, mut rest), arg| {
-                    arg.and_then(|(state, arg)| {
-                        let PatType { attrs, pat, ty, .. } = arg;
-                        match state {
-                            State::Required => {
-                                required.push(ty);
-                                Ok(())
-                            }
-                            State::Optional => {
-                                optional.push(ty);
+                    let arg = match arg {
+                        Ok(arg) => arg,
+                        Err(error) => {
+                            push_error(error);
+                            return (required, optional, rest);
+                        }
+                    };
+                    let (state, arg) = arg;
+                    let PatType { attrs, pat, ty, .. } = arg;
+                    let result = match state {
+                        State::Required => {
+                            required.push(ty);
+                            Ok(())
+                        }
+                        State::Optional => optional_default(&attrs).map(|default| {
+                            optional.push((ty, default));
+                        }),
+                        State::Rest(RestTy::List) => {
+                            if rest.is_none() {
+                                rest = Some(Rest::List(ty));
                                 Ok(())
+                            } else {
+                                Err(syn::Error::new(ty.span(), "no more arguments may appear after using the `rest` attribute"))
                             }
-                            State::Rest(RestTy::List) => {
-                                if rest.is_none() {
-                                    rest = Some(Rest::List(ty));
-                                    Ok(())
-                                } else {
-                                    Err(syn::Error::new(ty.span(), "no more arguments may appear after using the `rest` attribute"))
-                                }
-                            }
-                            State::Rest(RestTy::Keyword) => {
+                        }
+                        State::Rest(RestTy::Keyword) => {
+                            if attrs.iter().any(|attr| attr.path().is_ident("rest")) {
+                                Err(syn::Error::new(
+                                    ty.span(),
+                                    "the `rest` attribute cannot be mixed with keyword arguments",
+                                ))
+                            } else {
                                 if let Pat::Ident(PatIdent { ident, .. }) = *pat.clone() {
                                     Some(ident.to_string())
                                 } else {
@@ -163,28 +230,39 @@ impl TryFrom<ItemFn> for FnArgs {
                                         })
                                     })
                                     .ok_or_else(|| syn::Error::new(pat.span(), "Unable to create a keyword for this argument. Either bind the pattern to an identifier or use `#[keyword = \"keyword\"]` to set the keyword identifier."))
-                                    .map(|ident| {
+                                    .and_then(|ident| keyword_default(&attrs).map(|default| (ident, default)))
+                                    .map(|(ident, default)| {
                                         match &mut rest {
                                             Some(Rest::List(_)) => unreachable!(),
                                             Some(Rest::Keyword(keywords)) => keywords,
                                             None => {
                                                 rest = Some(Rest::Keyword(Vec::new()));
-                                                rest.as_mut().map(|rest| match rest { Rest::Keyword(vec) => vec, _ => unreachable!("it should be set above") }).unwrap() 
+                                                rest.as_mut().map(|rest| match rest { Rest::Keyword(vec) => vec, _ => unreachable!("it should be set above") }).unwrap()
                                             },
                                         }
-                                        .push((ident, ty))
+                                        .push((ident, ty, default))
                                     })
                             }
                         }
-                            .map(|_| (required, optional, rest))
-                    })
+                    };
+                    if let Err(error) = result {
+                        push_error(error);
+                    }
+                    (required, optional, rest)
                 }
-            )
-            .map(|(required, optional, rest)| Self {
+            );
+
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(Self {
                 guile,
                 required: required.into_iter().map(|r| *r).collect(),
-                optional: optional.into_iter().map(|r| *r).collect(),
+                optional: optional
+                    .into_iter()
+                    .map(|(ty, default)| (*ty, default))
+                    .collect(),
                 rest,
-            })
+            }),
+        }
     }
 }
@@ -17,6 +17,7 @@
 
 mod fn_args;
 mod macro_args;
+mod record;
 
 use {
     crate::{
@@ -29,16 +30,35 @@ use {
     quote::quote,
     std::{borrow::Cow, ffi::CString, iter},
     syn::{
-        Attribute, DeriveInput, Expr, ExprLit, ExprPath, FnArg, GenericParam, Generics, Ident,
-        ItemFn, Lifetime, LifetimeParam, Lit, LitCStr, MetaNameValue, PatType, Path, Receiver,
-        Signature, parse_quote, spanned::Spanned,
+        Attribute, Data, DataEnum, DataStruct, DataUnion, DeriveInput, Expr, ExprLit, ExprPath,
+        FnArg, GenericParam, Generics, Ident, ItemFn, Lifetime, LifetimeParam, Lit, LitCStr,
+        MetaNameValue, PatType, Path, Receiver, Signature, parse_quote,
+        spanned::Spanned,
+        visit_mut::{self, VisitMut},
     },
 };
 
+/// Evaluate `a` and `b`, reporting every failure at once (via [syn::Error::combine]) instead of
+/// stopping at whichever fails first — so a caller with several independently-parsed pieces (e.g.
+/// the macro's arguments alongside its annotated item, or one attribute alongside another) sees
+/// all of their mistakes in a single compile.
+fn combine2<A, B>(
+    a: Result<A, syn::Error>,
+    b: Result<B, syn::Error>,
+) -> Result<(A, B), syn::Error> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok((a, b)),
+        (Err(mut a), Err(b)) => {
+            a.combine(b);
+            Err(a)
+        }
+        (Err(e), _) | (_, Err(e)) => Err(e),
+    }
+}
+
 #[proc_macro_attribute]
 pub fn guile_fn(args: TokenStream, input: TokenStream) -> TokenStream {
-    syn::parse::<macro_args::Args>(args)
-        .and_then(|args| syn::parse::<ItemFn>(input).map(|input| (args, input)))
+    combine2(syn::parse::<macro_args::Args>(args), syn::parse::<ItemFn>(input))
         .and_then(|(args, mut input)| {
             let Config {
                 guile_ident,
@@ -73,8 +93,74 @@ pub fn guile_fn(args: TokenStream, input: TokenStream) -> TokenStream {
                         let optional_idents = (0..optional_len).map(|i| format!("optional_{i}")).map(|i| Ident::new(&i, Span::call_site())).collect::<Vec<_>>();
                         let rest_ident = has_rest.then(|| Ident::new("rest", Span::call_site())).into_iter().collect::<Vec<_>>();
 
-                        let keyword_idxs = rest.as_ref().and_then(|rest| match rest {
-                            Rest::Keyword(keywords) => Some((required_len + optional_len..required_len + optional_len + keywords.len()).collect::<Vec<_>>()),
+                        // Arguments with an `#[optional = expr]`/`#[default = expr]` attribute are bound
+                        // directly to their declared type (falling back to `expr` when the caller omits
+                        // them); the rest keep the old `Option<T>`-wrapped behavior.
+                        let optional_bindings = optional_idents
+                            .iter()
+                            .zip(optional_idxs)
+                            .zip(&optional)
+                            .map(|((ident, idx), (_, default))| match default {
+                                Some(default) => quote! {
+                                    let #ident = ::std::mem::ManuallyDrop::new(if unsafe { #garguile_root::sys::SCM_UNBNDP(#ident) } {
+                                        #default
+                                    } else {
+                                        #garguile_root::scm::TryFromScm::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#ident, guile), #guile_ident, #idx, guile)
+                                    });
+                                },
+                                None => quote! {
+                                    let #ident = <::std::option::Option<_> as #garguile_root::scm::TryFromScm>::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#ident, guile), #guile_ident, #idx, guile).map(::std::mem::ManuallyDrop::new);
+                                },
+                            })
+                            .collect::<Vec<_>>();
+                        let optional_call_args = optional_idents
+                            .iter()
+                            .zip(&optional)
+                            .map(|(ident, (_, default))| if default.is_some() {
+                                quote! { &#ident, }
+                            } else {
+                                quote! { #ident.as_deref(), }
+                            })
+                            .collect::<Vec<_>>();
+
+                        // Keywords with a `#[default = expr]` attribute are bound directly to their
+                        // declared type (falling back to `expr` when the caller omits them), same as
+                        // a defaulted `#[optional]` argument; the rest keep the old `Option<T>`-wrapped
+                        // behavior.
+                        let keyword_bindings = rest.as_ref().and_then(|rest| match rest {
+                            Rest::Keyword(keywords) => Some(
+                                (0..keywords.len())
+                                    .map(|i| Ident::new(&format!("keyword_{i}"), Span::call_site()))
+                                    .zip(required_len + optional_len..required_len + optional_len + keywords.len())
+                                    .zip(keywords)
+                                    .map(|((ident, idx), (_, _, default))| match default {
+                                        Some(default) => quote! {
+                                            let #ident = ::std::mem::ManuallyDrop::new(if unsafe { #garguile_root::sys::SCM_UNBNDP(#ident) } {
+                                                #default
+                                            } else {
+                                                #garguile_root::scm::TryFromScm::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#ident, guile), #guile_ident, #idx, guile)
+                                            });
+                                        },
+                                        None => quote! {
+                                            let #ident = <::std::option::Option<_> as #garguile_root::scm::TryFromScm>::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#ident, guile), #guile_ident, #idx, guile).map(::std::mem::ManuallyDrop::new);
+                                        },
+                                    })
+                                    .collect::<Vec<_>>(),
+                            ),
+                            Rest::List(_) => None,
+                        }).into_iter();
+                        let keyword_call_args = rest.as_ref().and_then(|rest| match rest {
+                            Rest::Keyword(keywords) => Some(
+                                (0..keywords.len())
+                                    .map(|i| Ident::new(&format!("keyword_{i}"), Span::call_site()))
+                                    .zip(keywords)
+                                    .map(|(ident, (_, _, default))| if default.is_some() {
+                                        quote! { &#ident, }
+                                    } else {
+                                        quote! { #ident.as_deref(), }
+                                    })
+                                    .collect::<Vec<_>>(),
+                            ),
                             Rest::List(_) => None,
                         }).into_iter();
                         let keyword_static_idents = rest.as_ref().and_then(|rest| match rest {
@@ -86,7 +172,7 @@ pub fn guile_fn(args: TokenStream, input: TokenStream) -> TokenStream {
                             Rest::List(_) => None,
                         }).into_iter().collect::<Vec<_>>();
                         let keyword_symbols = rest.as_ref().and_then(|rest| match rest {
-                            Rest::Keyword(keywords) => Some(keywords.iter().map(|(sym, _)| sym).collect::<Vec<_>>()),
+                            Rest::Keyword(keywords) => Some(keywords.iter().map(|(sym, _, _)| sym).collect::<Vec<_>>()),
                             Rest::List(_) => None,
                         }).into_iter();
 
@@ -117,7 +203,7 @@ pub fn guile_fn(args: TokenStream, input: TokenStream) -> TokenStream {
                                         let guile = unsafe { #garguile_root::Guile::new_unchecked_ref() };
 
                                         #(let #required_idents = ::std::mem::ManuallyDrop::new(#garguile_root::scm::TryFromScm::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#required_idents, guile), #guile_ident, #required_idxs, guile));)*
-                                        #(let #optional_idents = <::std::option::Option<_> as #garguile_root::scm::TryFromScm>::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#optional_idents, guile), #guile_ident, #optional_idxs, guile).map(::std::mem::ManuallyDrop::new);)*
+                                        #(#optional_bindings)*
                                         #(#(static #keyword_static_idents: ::std::sync::LazyLock<::std::sync::atomic::AtomicPtr<#garguile_root::sys::scm_unused_struct>> = ::std::sync::LazyLock::new(|| {
                                             const SYMBOL: &'static ::std::primitive::str = #keyword_symbols;
                                             unsafe { #garguile_root::sys::scm_symbol_to_keyword(#garguile_root::sys::scm_from_utf8_symboln(SYMBOL.as_bytes().as_ptr().cast(), SYMBOL.len()))}.into()
@@ -128,14 +214,14 @@ pub fn guile_fn(args: TokenStream, input: TokenStream) -> TokenStream {
                                             #(#keyword_static_idents.load(::std::sync::atomic::Ordering::SeqCst), &raw mut #keyword_idents,)*
                                             #garguile_root::sys::SCM_UNDEFINED,
                                         ); }
-                                        #(let #keyword_idents = <::std::option::Option<_> as #garguile_root::scm::TryFromScm>::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#keyword_idents, guile), #guile_ident, #keyword_idxs, guile).map(::std::mem::ManuallyDrop::new);)*)*
+                                        #(#keyword_bindings)*)*
                                         #(let #rest_ident: ::std::mem::ManuallyDrop<#garguile_root::collections::list::List<_>> = ::std::mem::ManuallyDrop::new(#garguile_root::scm::TryFromScm::from_scm_or_throw(#garguile_root::scm::Scm::from_ptr(#rest_list, guile), #guile_ident, #rest_idx, guile));)*
 
                                         let ret = #ident(
                                             #guile
                                             #(&#required_idents,)*
-                                            #(#optional_idents.as_deref(),)*
-                                            #(#(#keyword_idents.as_deref(),)*)*
+                                            #(#optional_call_args)*
+                                            #(#(#keyword_call_args)*)*
                                             #(&#rest_enabled_ident)*
                                         );
                                         #garguile_root::reference::ReprScm::as_ptr(&#garguile_root::scm::ToScm::to_scm(ret, guile))
@@ -169,6 +255,7 @@ pub fn guile_fn(args: TokenStream, input: TokenStream) -> TokenStream {
                             attrs.retain(|attr| {
                                 !(attr.path().is_ident("guile")
                                     || attr.path().is_ident("optional")
+                                    || attr.path().is_ident("default")
                                     || attr.path().is_ident("rest")
                                     || attr.path().is_ident("keyword"))
                             })
@@ -263,20 +350,38 @@ pub fn foreign_object(input: TokenStream) -> TokenStream {
                             #where_clause
                             {
                                 unsafe fn get_or_create_type() -> #garguile_root::sys::SCM {
-                                    static OBJECT_TYPE: ::std::sync::LazyLock<::std::sync::atomic::AtomicPtr<#garguile_root::sys::scm_unused_struct>>
-                                        = ::std::sync::LazyLock::new(|| {
-                                            let guile = unsafe { #garguile_root::Guile::new_unchecked_ref() };
-                                            let name = #garguile_root::symbol::Symbol::from_str(#ty_name_str, guile);
-                                            unsafe {
-                                                #garguile_root::sys::scm_make_foreign_object_type(
-                                                    #garguile_root::reference::ReprScm::as_ptr(&name),
-                                                    #garguile_root::foreign_object::slots(),
-                                                    ::std::option::Option::None,
-                                                )
-                                            }.into()
-                                        });
+                                    unsafe { #garguile_root::foreign_object::get_or_create_type::<Self>(#ty_name_str) }
+                                }
+                            }
+                        }
+                    })
+            },
+        )
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-                                    OBJECT_TYPE.load(::std::sync::atomic::Ordering::Acquire)
+#[proc_macro_derive(Finalized, attributes(garguile_root))]
+pub fn finalized(input: TokenStream) -> TokenStream {
+    syn::parse::<DeriveInput>(input)
+        .and_then(
+            |DeriveInput {
+                 attrs,
+                 ident,
+                 generics,
+                 ..
+             }| {
+                let ty_name_str = ident.to_string().to_case(Case::Kebab);
+                garguile_root(&attrs)
+                    .map(|garguile_root| {
+                        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+                        quote! {
+                            impl #impl_generics #garguile_root::foreign_object::Finalized for #ident #ty_generics
+                            #where_clause
+                            {
+                                unsafe fn get_or_create_finalized_type() -> #garguile_root::sys::SCM {
+                                    unsafe { #garguile_root::foreign_object::get_or_create_finalized_type::<Self>(#ty_name_str) }
                                 }
                             }
                         }
@@ -287,6 +392,40 @@ pub fn foreign_object(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Give every anonymous lifetime (`'_`) appearing in `generics` a fresh, explicit name (`'de0`,
+/// `'de1`, ...) and declare it as a proper [LifetimeParam], returning the lifetimes introduced in
+/// the order they were first seen.
+///
+/// `'_` can be written in a field's bounds (e.g. `T: Trait<'_>`) but isn't legal to *declare* in an
+/// `impl` header, so left alone it would make the derived impl fail to parse. Mirrors
+/// `mockall_derive`'s `deanonymize_lifetime`.
+fn deanonymize_lifetimes(generics: &mut Generics) -> Vec<Lifetime> {
+    struct Deanonymizer {
+        fresh: Vec<Lifetime>,
+    }
+    impl VisitMut for Deanonymizer {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+            if lifetime.ident == "_" {
+                let fresh = Lifetime::new(&format!("'de{}", self.fresh.len()), lifetime.span());
+                *lifetime = fresh.clone();
+                self.fresh.push(fresh);
+            }
+        }
+    }
+
+    let mut deanonymizer = Deanonymizer { fresh: Vec::new() };
+    visit_mut::visit_generics_mut(&mut deanonymizer, generics);
+
+    for lifetime in deanonymizer.fresh.iter().rev() {
+        generics.params.insert(
+            0,
+            GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())),
+        );
+    }
+
+    deanonymizer.fresh
+}
+
 fn add_lifetime(lt: Lifetime, mut generics: Generics) -> Generics {
     if !generics.params.iter().any(|param| {
         matches!(param, GenericParam::Lifetime(LifetimeParam {
@@ -311,18 +450,28 @@ pub fn to_scm(input: TokenStream) -> TokenStream {
                  generics,
                  ..
              }| {
-                garguile_root(&attrs)
-                    .and_then(|garguile_root| guile_mode_lt(&attrs)
-                              .map(|ident| Lifetime {
-                                  apostrophe: Span::call_site(),
-                                  ident: ident.into_owned(),
-                              })
-                              .map(|gm| (garguile_root, gm)))
+                combine2(garguile_root(&attrs), guile_mode_lt(&attrs))
+                    .map(|(garguile_root, ident)| {
+                        let gm = Lifetime {
+                            apostrophe: Span::call_site(),
+                            ident: ident.into_owned(),
+                        };
+                        (garguile_root, gm)
+                    })
                     .map(|(garguile_root, gm)| {
+                        let mut generics = generics;
+                        let anonymous_lifetimes = deanonymize_lifetimes(&mut generics);
+
                         let (_, ty_generics, _) = generics.split_for_impl();
                         let ty_generics = quote! { #ty_generics };
 
-                        let generics = add_lifetime(gm.clone(), generics);
+                        let mut generics = add_lifetime(gm.clone(), generics);
+                        if !anonymous_lifetimes.is_empty() {
+                            let where_clause = generics.make_where_clause();
+                            anonymous_lifetimes.iter().for_each(|lifetime| {
+                                where_clause.predicates.push(parse_quote! { #gm: #lifetime });
+                            });
+                        }
                         let (impl_generics, _, where_clause) = generics.split_for_impl();
 
                         quote! {
@@ -355,33 +504,42 @@ pub fn try_from_scm(input: TokenStream) -> TokenStream {
                  generics,
                  ..
              }| {
-                garguile_root(&attrs)
-                    .and_then(|garguile_root| {
-                        guile_mode_lt(&attrs)
-                            .map(|ident| Lifetime {
-                                apostrophe: Span::call_site(),
-                                ident: ident.into_owned(),
-                            })
-                            .map(|gm| (garguile_root, gm))
-                    })
-                    .and_then(|(root, gm)| get_last_attr(&attrs, "ty_name", |expr| match expr {
+                combine2(
+                    combine2(garguile_root(&attrs), guile_mode_lt(&attrs)),
+                    get_last_attr(&attrs, "ty_name", |expr| match expr {
                         Expr::Lit(ExprLit { lit: Lit::CStr(ty_name), .. }) => Ok(ty_name),
                         expr => Err(syn::Error::new(expr.span(), "expected c string literal: `ty_name = c\"foo\"`"))
-                    }, LitCStr::new(&CString::new(ident.to_string().to_case(Case::Kebab)).unwrap(), Span::call_site()))
-                    .map(|ty_name| (root, gm, ty_name)))
+                    }, LitCStr::new(&CString::new(ident.to_string().to_case(Case::Kebab)).unwrap(), Span::call_site())),
+                )
+                    .map(|((garguile_root, ident), ty_name)| {
+                        let gm = Lifetime {
+                            apostrophe: Span::call_site(),
+                            ident: ident.into_owned(),
+                        };
+                        (garguile_root, gm, ty_name)
+                    })
                     .map(|(garguile_root, gm, ty_name)| {
+                        let mut generics = generics;
+                        let anonymous_lifetimes = deanonymize_lifetimes(&mut generics);
+
                         let (_, ty_generics, _) = generics.split_for_impl();
                         let ty_generics = quote! { #ty_generics };
 
-                        let generics = add_lifetime(gm.clone(), generics);
+                        let mut generics = add_lifetime(gm.clone(), generics);
+                        if !anonymous_lifetimes.is_empty() {
+                            let where_clause = generics.make_where_clause();
+                            anonymous_lifetimes.iter().for_each(|lifetime| {
+                                where_clause.predicates.push(parse_quote! { #gm: #lifetime });
+                            });
+                        }
                         let (impl_generics, _, where_clause) = generics.split_for_impl();
 
                         quote! {
                             impl #impl_generics #garguile_root::scm::TryFromScm<#gm> for #ident #ty_generics
                             #where_clause
                             {
-                                fn type_name() -> ::std::borrow::Cow<'static, ::std::ffi::CStr> {
-                                    ::std::borrow::Cow::Borrowed(#ty_name)
+                                fn type_name() -> #garguile_root::type_name::TypeName {
+                                    #garguile_root::type_name::TypeName::from_static(#ty_name)
                                 }
 
                                 fn predicate(scm: &#garguile_root::scm::Scm<#gm>, _: &#gm #garguile_root::Guile) -> bool {
@@ -417,3 +575,345 @@ pub fn try_from_scm(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+fn to_scm_record_body(
+    data: &Data,
+    garguile_root: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    match data {
+        Data::Struct(DataStruct { fields, .. }) => record::fields(fields).map(|parsed| {
+            let pattern = record::destructure_pattern(fields, &parsed);
+            let expr = record::to_scm(fields, &parsed, garguile_root);
+            quote! {
+                let Self #pattern = self;
+                #expr
+            }
+        }),
+        Data::Enum(DataEnum { variants, .. }) => {
+            let mut errors: Option<syn::Error> = None;
+            let mut push_error = |error: syn::Error| match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            };
+
+            let arms = variants
+                .iter()
+                .filter_map(|variant| match record::fields(&variant.fields) {
+                    Ok(parsed) => {
+                        let variant_ident = &variant.ident;
+                        let pattern = record::destructure_pattern(&variant.fields, &parsed);
+                        let payload = record::to_scm(&variant.fields, &parsed, garguile_root);
+                        let tag = variant_ident.to_string().to_case(Case::Kebab);
+                        Some(quote! {
+                            Self::#variant_ident #pattern => #garguile_root::scm::ToScm::to_scm(
+                                #garguile_root::collections::pair::Pair::new(
+                                    #garguile_root::symbol::Symbol::from_str(#tag, guile),
+                                    #payload,
+                                    guile,
+                                ),
+                                guile,
+                            ),
+                        })
+                    }
+                    Err(error) => {
+                        push_error(error);
+                        None
+                    }
+                })
+                .collect::<proc_macro2::TokenStream>();
+
+            match errors {
+                Some(errors) => Err(errors),
+                None => Ok(quote! { match self { #arms } }),
+            }
+        }
+        Data::Union(DataUnion { union_token, .. }) => Err(syn::Error::new(
+            union_token.span(),
+            "ToScmRecord cannot be derived for unions",
+        )),
+    }
+}
+
+/// Build a `#[derive(ToScm)]`-alike impl that, instead of boxing `self` opaquely as a
+/// [ForeignObject][crate::foreign_object::ForeignObject], maps it field-by-field onto a plain
+/// Scheme value: a named-field struct/variant becomes an association list keyed by each field's
+/// (renamed or kebab-cased) name, a tuple struct/variant becomes a list of its fields in
+/// declaration order, and an enum becomes a tagged `(variant-name . payload)` pair.
+#[proc_macro_derive(ToScmRecord, attributes(garguile_root, guile_mode_lt, rename, skip))]
+pub fn to_scm_record(input: TokenStream) -> TokenStream {
+    syn::parse::<DeriveInput>(input)
+        .and_then(
+            |DeriveInput {
+                 attrs,
+                 ident,
+                 generics,
+                 data,
+                 ..
+             }| {
+                combine2(garguile_root(&attrs), guile_mode_lt(&attrs))
+                    .map(|(garguile_root, ident)| {
+                        let gm = Lifetime {
+                            apostrophe: Span::call_site(),
+                            ident: ident.into_owned(),
+                        };
+                        (garguile_root, gm)
+                    })
+                    .and_then(|(garguile_root, gm)| {
+                        to_scm_record_body(&data, &garguile_root)
+                            .map(|body| (garguile_root, gm, body))
+                    })
+                    .map(|(garguile_root, gm, body)| {
+                        let mut generics = generics;
+                        let anonymous_lifetimes = deanonymize_lifetimes(&mut generics);
+
+                        let (_, ty_generics, _) = generics.split_for_impl();
+                        let ty_generics = quote! { #ty_generics };
+
+                        let mut generics = add_lifetime(gm.clone(), generics);
+                        if !anonymous_lifetimes.is_empty() {
+                            let where_clause = generics.make_where_clause();
+                            anonymous_lifetimes.iter().for_each(|lifetime| {
+                                where_clause.predicates.push(parse_quote! { #gm: #lifetime });
+                            });
+                        }
+                        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+                        quote! {
+                            impl #impl_generics #garguile_root::scm::ToScm<#gm> for #ident #ty_generics
+                            #where_clause
+                            {
+                                fn to_scm(self, guile: &'gm #garguile_root::Guile) -> #garguile_root::scm::Scm<'gm> {
+                                    #body
+                                }
+                            }
+                        }
+                    })
+            },
+        )
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The representation every `TryFromScmRecord`/`ToScmRecord` enum is tagged with: `(symbol .
+/// payload)`, where the symbol is the variant's kebab-case name.
+fn tagged_pair_ty(garguile_root: &Path) -> proc_macro2::TokenStream {
+    quote! { #garguile_root::collections::pair::Pair<#garguile_root::symbol::Symbol, #garguile_root::scm::Scm> }
+}
+
+fn try_from_scm_record_predicate_body(
+    data: &Data,
+    garguile_root: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    match data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            record::fields(fields).map(|parsed| record::predicate(fields, &parsed, garguile_root))
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            let mut errors: Option<syn::Error> = None;
+            let mut push_error = |error: syn::Error| match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            };
+
+            let checks = variants
+                .iter()
+                .filter_map(|variant| match record::fields(&variant.fields) {
+                    Ok(parsed) => {
+                        let tag = variant.ident.to_string().to_case(Case::Kebab);
+                        let payload_predicate =
+                            record::predicate(&variant.fields, &parsed, garguile_root);
+                        Some(quote! {
+                            || (
+                                #garguile_root::scm::ToScm::to_scm(tag, guile)
+                                    == #garguile_root::scm::ToScm::to_scm(#garguile_root::symbol::Symbol::from_str(#tag, guile), guile)
+                                && { let scm = &payload; #payload_predicate }
+                            )
+                        })
+                    }
+                    Err(error) => {
+                        push_error(error);
+                        None
+                    }
+                })
+                .collect::<proc_macro2::TokenStream>();
+
+            match errors {
+                Some(errors) => Err(errors),
+                None => {
+                    let pair_ty = tagged_pair_ty(garguile_root);
+                    Ok(quote! {
+                        <#pair_ty as #garguile_root::scm::TryFromScm>::predicate(scm, guile) && {
+                            let pair = unsafe {
+                                <#pair_ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(scm.copy_unchecked(), guile)
+                            };
+                            let tag = pair.as_car().copied();
+                            let payload = unsafe { pair.as_cdr().copy_unchecked() };
+                            false #checks
+                        }
+                    })
+                }
+            }
+        }
+        Data::Union(DataUnion { union_token, .. }) => Err(syn::Error::new(
+            union_token.span(),
+            "TryFromScmRecord cannot be derived for unions",
+        )),
+    }
+}
+
+fn try_from_scm_record_from_scm_body(
+    data: &Data,
+    garguile_root: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    match data {
+        Data::Struct(DataStruct { fields, .. }) => record::fields(fields)
+            .map(|parsed| record::from_scm(quote! { Self }, fields, &parsed, garguile_root)),
+        Data::Enum(DataEnum { variants, .. }) => {
+            let mut errors: Option<syn::Error> = None;
+            let mut push_error = |error: syn::Error| match &mut errors {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            };
+
+            let arms = variants
+                .iter()
+                .filter_map(|variant| match record::fields(&variant.fields) {
+                    Ok(parsed) => {
+                        let variant_ident = &variant.ident;
+                        let tag = variant_ident.to_string().to_case(Case::Kebab);
+                        let construct = record::from_scm(
+                            quote! { Self::#variant_ident },
+                            &variant.fields,
+                            &parsed,
+                            garguile_root,
+                        );
+                        Some(quote! {
+                            if #garguile_root::scm::ToScm::to_scm(tag, guile)
+                                == #garguile_root::scm::ToScm::to_scm(#garguile_root::symbol::Symbol::from_str(#tag, guile), guile)
+                            {
+                                let scm = payload;
+                                #construct
+                            } else
+                        })
+                    }
+                    Err(error) => {
+                        push_error(error);
+                        None
+                    }
+                })
+                .collect::<proc_macro2::TokenStream>();
+
+            match errors {
+                Some(errors) => Err(errors),
+                None => {
+                    let pair_ty = tagged_pair_ty(garguile_root);
+                    Ok(quote! {
+                        let pair = unsafe {
+                            <#pair_ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(scm, guile)
+                        };
+                        let tag = pair.as_car().copied();
+                        let payload = unsafe { pair.as_cdr().copy_unchecked() };
+                        #arms
+                        {
+                            ::std::unreachable!("`predicate` should have already checked the tag")
+                        }
+                    })
+                }
+            }
+        }
+        Data::Union(DataUnion { union_token, .. }) => Err(syn::Error::new(
+            union_token.span(),
+            "TryFromScmRecord cannot be derived for unions",
+        )),
+    }
+}
+
+/// Build a `#[derive(TryFromScm)]`-alike impl that reads a plain Scheme value field-by-field
+/// back into `Self`, the inverse of [ToScmRecord]. See [to_scm_record] for the representation.
+#[proc_macro_derive(
+    TryFromScmRecord,
+    attributes(garguile_root, guile_mode_lt, ty_name, rename, skip)
+)]
+pub fn try_from_scm_record(input: TokenStream) -> TokenStream {
+    syn::parse::<DeriveInput>(input)
+        .and_then(
+            |DeriveInput {
+                 attrs,
+                 ident,
+                 generics,
+                 data,
+                 ..
+             }| {
+                combine2(
+                    combine2(garguile_root(&attrs), guile_mode_lt(&attrs)),
+                    get_last_attr(
+                        &attrs,
+                        "ty_name",
+                        |expr| match expr {
+                            Expr::Lit(ExprLit { lit: Lit::CStr(ty_name), .. }) => Ok(ty_name),
+                            expr => Err(syn::Error::new(
+                                expr.span(),
+                                "expected c string literal: `ty_name = c\"foo\"`",
+                            )),
+                        },
+                        LitCStr::new(
+                            &CString::new(ident.to_string().to_case(Case::Kebab)).unwrap(),
+                            Span::call_site(),
+                        ),
+                    ),
+                )
+                    .map(|((garguile_root, ident), ty_name)| {
+                        let gm = Lifetime {
+                            apostrophe: Span::call_site(),
+                            ident: ident.into_owned(),
+                        };
+                        (garguile_root, gm, ty_name)
+                    })
+                    .and_then(|(garguile_root, gm, ty_name)| {
+                        combine2(
+                            try_from_scm_record_predicate_body(&data, &garguile_root),
+                            try_from_scm_record_from_scm_body(&data, &garguile_root),
+                        )
+                        .map(|(predicate_body, from_scm_body)| {
+                            (garguile_root, gm, ty_name, predicate_body, from_scm_body)
+                        })
+                    })
+                    .map(|(garguile_root, gm, ty_name, predicate_body, from_scm_body)| {
+                        let mut generics = generics;
+                        let anonymous_lifetimes = deanonymize_lifetimes(&mut generics);
+
+                        let (_, ty_generics, _) = generics.split_for_impl();
+                        let ty_generics = quote! { #ty_generics };
+
+                        let mut generics = add_lifetime(gm.clone(), generics);
+                        if !anonymous_lifetimes.is_empty() {
+                            let where_clause = generics.make_where_clause();
+                            anonymous_lifetimes.iter().for_each(|lifetime| {
+                                where_clause.predicates.push(parse_quote! { #gm: #lifetime });
+                            });
+                        }
+                        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+                        quote! {
+                            impl #impl_generics #garguile_root::scm::TryFromScm<#gm> for #ident #ty_generics
+                            #where_clause
+                            {
+                                fn type_name() -> #garguile_root::type_name::TypeName {
+                                    #garguile_root::type_name::TypeName::from_static(#ty_name)
+                                }
+
+                                fn predicate(scm: &#garguile_root::scm::Scm<#gm>, guile: &#gm #garguile_root::Guile) -> bool {
+                                    #predicate_body
+                                }
+
+                                unsafe fn from_scm_unchecked(scm: #garguile_root::scm::Scm<#gm>, guile: &#gm #garguile_root::Guile) -> Self {
+                                    #from_scm_body
+                                }
+                            }
+                        }
+                    })
+            },
+        )
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
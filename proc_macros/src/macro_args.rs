@@ -32,15 +32,46 @@ mod keywords {
     custom_keyword!(struct_ident);
     custom_keyword!(doc);
     custom_keyword!(garguile_root);
+    custom_keyword!(rename);
 
     custom_keyword!(r#false);
 }
 
+/// Parse a `rename = "..."` value into the [Case] it names.
+///
+/// Only the casings that come up in practice for Guile identifiers are recognized; anything else
+/// is a compile error listing the supported names, rather than silently falling back to kebab.
+fn parse_case(lit: &LitStr) -> Result<Case, syn::Error> {
+    match lit.value().as_str() {
+        "kebab" => Ok(Case::Kebab),
+        "snake" => Ok(Case::Snake),
+        "camel" => Ok(Case::Camel),
+        "pascal" => Ok(Case::Pascal),
+        "screaming-snake" => Ok(Case::ScreamingSnake),
+        "screaming-kebab" => Ok(Case::Cobol),
+        "title" => Ok(Case::Title),
+        "train" => Ok(Case::Train),
+        "flat" => Ok(Case::Flat),
+        "upper-flat" => Ok(Case::UpperFlat),
+        "upper" => Ok(Case::Upper),
+        "lower" => Ok(Case::Lower),
+        other => Err(syn::Error::new(
+            lit.span(),
+            format!(
+                "unknown casing {other:?}; expected one of \"kebab\", \"snake\", \"camel\", \
+                 \"pascal\", \"screaming-snake\", \"screaming-kebab\", \"title\", \"train\", \
+                 \"flat\", \"upper-flat\", \"upper\", \"lower\""
+            ),
+        )),
+    }
+}
+
 enum Key {
     GuileIdent,
     StructIdent,
     Doc,
     GarguileRoot,
+    Rename,
 }
 impl Parse for Key {
     fn parse(input: ParseStream) -> Result<Self, syn::Error> {
@@ -59,6 +90,8 @@ impl Parse for Key {
             input
                 .parse::<keywords::garguile_root>()
                 .map(|_| Self::GarguileRoot)
+        } else if lookahead.peek(keywords::rename) {
+            input.parse::<keywords::rename>().map(|_| Self::Rename)
         } else {
             Err(lookahead.error())
         }
@@ -70,6 +103,7 @@ enum Arg {
     StructIdent(Ident),
     Doc(Option<String>),
     GarguileRoot(Path),
+    Rename(Case),
 }
 impl Parse for Arg {
     fn parse(input: ParseStream) -> Result<Self, syn::Error> {
@@ -105,6 +139,10 @@ impl Parse for Arg {
             Key::GarguileRoot => <Token![=]>::parse(input)
                 .and_then(|_| <Path as Parse>::parse(input))
                 .map(Self::GarguileRoot),
+            Key::Rename => <Token![=]>::parse(input)
+                .and_then(|_| <LitStr as Parse>::parse(input))
+                .and_then(|lit| parse_case(&lit))
+                .map(Self::Rename),
         })
     }
 }
@@ -131,7 +169,7 @@ impl Config {
             ..
         }: &ItemFn,
     ) -> Self {
-        let (guile_ident, struct_ident, doc, garguile_root) = args.0.into_iter().fold(
+        let (guile_ident, struct_ident, doc, garguile_root, rename) = args.0.into_iter().fold(
             (
                 None,
                 None,
@@ -160,6 +198,7 @@ impl Config {
                 )
                 .filter(|docs| !docs.is_empty()),
                 None,
+                None,
             ),
             |mut accum, arg| {
                 match arg {
@@ -167,6 +206,7 @@ impl Config {
                     Arg::StructIdent(ident) => accum.1 = Some(ident),
                     Arg::Doc(doc) => accum.2 = doc,
                     Arg::GarguileRoot(root) => accum.3 = Some(root),
+                    Arg::Rename(case) => accum.4 = Some(case),
                 }
                 accum
             },
@@ -174,8 +214,9 @@ impl Config {
 
         let ident = LazyCell::new(|| ident.to_string());
         Self {
-            guile_ident: guile_ident
-                .unwrap_or_else(|| CString::new(ident.to_case(Case::Kebab)).unwrap()),
+            guile_ident: guile_ident.unwrap_or_else(|| {
+                CString::new(ident.to_case(rename.unwrap_or(Case::Kebab))).unwrap()
+            }),
             struct_ident: struct_ident
                 .unwrap_or_else(|| Ident::new(&ident.to_case(Case::Pascal), Span::call_site())),
             doc,
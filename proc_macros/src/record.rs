@@ -0,0 +1,355 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Field-mapping helpers shared by the `ToScmRecord`/`TryFromScmRecord` derives: a named-field
+//! struct or variant becomes an association list keyed by each field's (possibly renamed)
+//! kebab-case name; a tuple struct or variant (including a unit one, with zero fields) becomes a
+//! list of its fields in declaration order. `#[skip]` leaves a field out of the Scheme value
+//! entirely, reconstructing it with [Default] on the way back.
+
+use {
+    convert_case::{Case, Casing},
+    proc_macro2::TokenStream,
+    quote::{format_ident, quote},
+    syn::{Expr, ExprLit, Fields, Ident, Lit, MetaNameValue, Path, Type, spanned::Spanned},
+};
+
+/// One field of a struct or enum variant being derived over.
+pub struct Field {
+    /// The field's own name, or `None` for a tuple field.
+    pub ident: Option<Ident>,
+    /// A stable identifier to bind this field's value to, for both destructuring the original
+    /// value and reconstructing it: the field's own name if it has one, else `field{index}`.
+    pub binding: Ident,
+    /// The field's declared type.
+    pub ty: Type,
+    /// The key this field is stored under (its renamed or kebab-cased name), or `None` if
+    /// `#[skip]` left it out of the Scheme value.
+    pub key: Option<String>,
+}
+
+/// Read the last `#[rename = "..."]` attribute off `attrs`, if any.
+fn field_rename(attrs: &[syn::Attribute]) -> Result<Option<String>, syn::Error> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("rename"))
+        .map(|attr| {
+            attr.meta
+                .require_name_value()
+                .and_then(|MetaNameValue { value, .. }| match value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(rename),
+                        ..
+                    }) => Ok(rename.value()),
+                    value => Err(syn::Error::new(
+                        value.span(),
+                        "expected string literal: `#[rename = \"foo\"]`",
+                    )),
+                })
+        })
+        .next_back()
+        .transpose()
+}
+
+/// Parse every field of a struct or enum variant, reporting every malformed `#[rename]`
+/// attribute at once (via [syn::Error::combine]) rather than stopping at the first.
+pub fn fields(fields: &Fields) -> Result<Vec<Field>, syn::Error> {
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let syn::Field {
+                attrs, ident, ty, ..
+            } = field;
+            let binding = ident
+                .clone()
+                .unwrap_or_else(|| format_ident!("field{index}"));
+            let skip = attrs.iter().any(|attr| attr.path().is_ident("skip"));
+            let key = if skip {
+                None
+            } else {
+                match field_rename(attrs) {
+                    Ok(rename) => Some(rename.unwrap_or_else(|| match ident {
+                        Some(ident) => ident.to_string().to_case(Case::Kebab),
+                        None => index.to_string(),
+                    })),
+                    Err(error) => {
+                        push_error(error);
+                        None
+                    }
+                }
+            };
+
+            Field {
+                ident: ident.clone(),
+                binding,
+                ty: ty.clone(),
+                key,
+            }
+        })
+        .collect();
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(fields),
+    }
+}
+
+/// The pattern that destructures a value of this shape into each included field's
+/// [Field::binding], discarding skipped fields.
+pub fn destructure_pattern(fields: &Fields, parsed: &[Field]) -> TokenStream {
+    match fields {
+        Fields::Named(_) => {
+            let bindings = parsed.iter().map(
+                |Field {
+                     ident,
+                     binding,
+                     key,
+                     ..
+                 }| {
+                    if key.is_some() {
+                        quote! { #binding }
+                    } else {
+                        let ident = ident.as_ref().expect("named field");
+                        quote! { #ident: _ }
+                    }
+                },
+            );
+            quote! { { #(#bindings,)* } }
+        }
+        Fields::Unnamed(_) => {
+            let bindings = parsed.iter().map(|Field { binding, key, .. }| {
+                if key.is_some() {
+                    quote! { #binding }
+                } else {
+                    quote! { _ }
+                }
+            });
+            quote! { ( #(#bindings,)* ) }
+        }
+        Fields::Unit => quote! {},
+    }
+}
+
+/// The alist type every named-field record is stored as: `(symbol . value)` pairs, each value
+/// erased to [`Scm`][crate::scm::Scm] so fields of different concrete types can share one list.
+fn alist_ty(garguile_root: &Path) -> TokenStream {
+    quote! {
+        #garguile_root::collections::alist::AList<#garguile_root::symbol::Symbol, #garguile_root::scm::Scm>
+    }
+}
+
+/// The list type every tuple (or unit) record is stored as.
+fn list_ty(garguile_root: &Path) -> TokenStream {
+    quote! { #garguile_root::collections::list::List<#garguile_root::scm::Scm> }
+}
+
+fn alist_to_scm(parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    let entries = parsed.iter().filter_map(|Field { binding, key, .. }| {
+        key.as_ref().map(|key| {
+            quote! {
+                #garguile_root::collections::pair::Pair::new(
+                    #garguile_root::symbol::Symbol::from_str(#key, guile),
+                    #garguile_root::scm::ToScm::to_scm(#binding, guile),
+                    guile,
+                )
+            }
+        })
+    });
+
+    quote! {
+        #garguile_root::scm::ToScm::to_scm(
+            #garguile_root::collections::list::List::from_iter_ordered([#(#entries),*], guile),
+            guile,
+        )
+    }
+}
+
+fn list_to_scm(parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    let entries = parsed.iter().filter_map(|Field { binding, key, .. }| {
+        key.as_ref()
+            .map(|_| quote! { #garguile_root::scm::ToScm::to_scm(#binding, guile) })
+    });
+
+    quote! {
+        #garguile_root::scm::ToScm::to_scm(
+            #garguile_root::collections::list::List::from_iter_ordered([#(#entries),*], guile),
+            guile,
+        )
+    }
+}
+
+/// Build the expression for a value of this shape's `to_scm`, given its already-parsed fields,
+/// each bound to its [Field::binding] (see [destructure_pattern]). Assumes `guile: &Guile` is in
+/// scope.
+pub fn to_scm(fields: &Fields, parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    match fields {
+        Fields::Named(_) => alist_to_scm(parsed, garguile_root),
+        Fields::Unnamed(_) | Fields::Unit => list_to_scm(parsed, garguile_root),
+    }
+}
+
+fn alist_predicate(parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    let alist_ty = alist_ty(garguile_root);
+    let checks = parsed
+        .iter()
+        .filter_map(|Field { ty, key, .. }| {
+            key.as_ref().map(|key| {
+                quote! {
+                    alist
+                        .get(#garguile_root::symbol::Symbol::from_str(#key, guile))
+                        .is_some_and(|value| <#ty as #garguile_root::scm::TryFromScm>::predicate(&value, guile))
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if checks.is_empty() {
+        quote! { <#alist_ty as #garguile_root::scm::TryFromScm>::predicate(scm, guile) }
+    } else {
+        quote! {
+            <#alist_ty as #garguile_root::scm::TryFromScm>::predicate(scm, guile) && {
+                let alist = unsafe {
+                    <#alist_ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(scm.copy_unchecked(), guile)
+                };
+                true #(&& #checks)*
+            }
+        }
+    }
+}
+
+fn list_predicate(parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    let list_ty = list_ty(garguile_root);
+    let checks = parsed
+        .iter()
+        .filter(|field| field.key.is_some())
+        .map(|Field { ty, .. }| {
+            quote! {
+                items.next().is_some_and(|value| <#ty as #garguile_root::scm::TryFromScm>::predicate(&value, guile))
+            }
+        })
+        .collect::<Vec<_>>();
+    let len = checks.len();
+
+    if checks.is_empty() {
+        quote! {
+            <#list_ty as #garguile_root::scm::TryFromScm>::predicate(scm, guile) && {
+                let list = unsafe {
+                    <#list_ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(scm.copy_unchecked(), guile)
+                };
+                list.len() == #len
+            }
+        }
+    } else {
+        quote! {
+            <#list_ty as #garguile_root::scm::TryFromScm>::predicate(scm, guile) && {
+                let list = unsafe {
+                    <#list_ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(scm.copy_unchecked(), guile)
+                };
+                list.len() == #len && {
+                    let mut items = list.iter();
+                    true #(&& #checks)*
+                }
+            }
+        }
+    }
+}
+
+/// Build the `bool` expression for a value of this shape's `predicate`. Assumes `scm: &Scm` and
+/// `guile: &Guile` are in scope.
+pub fn predicate(fields: &Fields, parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    match fields {
+        Fields::Named(_) => alist_predicate(parsed, garguile_root),
+        Fields::Unnamed(_) | Fields::Unit => list_predicate(parsed, garguile_root),
+    }
+}
+
+fn alist_from_scm(constructor: TokenStream, parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    let alist_ty = alist_ty(garguile_root);
+    let has_included = parsed.iter().any(|field| field.key.is_some());
+    let inits = parsed.iter().map(|Field { ident, ty, key, .. }| {
+        let ident = ident.as_ref().expect("named field");
+        match key {
+            Some(key) => quote! {
+                #ident: unsafe {
+                    <#ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(
+                        alist.get(#garguile_root::symbol::Symbol::from_str(#key, guile)).unwrap().copy_unchecked(),
+                        guile,
+                    )
+                }
+            },
+            None => quote! { #ident: ::std::default::Default::default() },
+        }
+    });
+
+    if has_included {
+        quote! {
+            {
+                let alist = unsafe {
+                    <#alist_ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(scm, guile)
+                };
+                #constructor { #(#inits,)* }
+            }
+        }
+    } else {
+        quote! { #constructor { #(#inits,)* } }
+    }
+}
+
+fn list_from_scm(constructor: TokenStream, parsed: &[Field], garguile_root: &Path) -> TokenStream {
+    let list_ty = list_ty(garguile_root);
+    let has_included = parsed.iter().any(|field| field.key.is_some());
+    let inits = parsed.iter().map(|Field { ty, key, .. }| match key {
+        Some(_) => quote! {
+            unsafe { <#ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(items.next().unwrap(), guile) }
+        },
+        None => quote! { ::std::default::Default::default() },
+    });
+
+    if has_included {
+        quote! {
+            {
+                let mut items = unsafe {
+                    <#list_ty as #garguile_root::scm::TryFromScm>::from_scm_unchecked(scm, guile)
+                }.into_iter();
+                #constructor(#(#inits,)*)
+            }
+        }
+    } else {
+        quote! { #constructor(#(#inits,)*) }
+    }
+}
+
+/// Build the expression constructing `constructor` (e.g. `Self` or `Self::Variant`) for a value
+/// of this shape, reading `scm` (by value) back with [Default] filling in any `#[skip]`ped
+/// field. Assumes `scm: Scm` (by value) and `guile: &Guile` are in scope.
+pub fn from_scm(
+    constructor: TokenStream,
+    fields: &Fields,
+    parsed: &[Field],
+    garguile_root: &Path,
+) -> TokenStream {
+    match fields {
+        Fields::Named(_) => alist_from_scm(constructor, parsed, garguile_root),
+        Fields::Unnamed(_) => list_from_scm(constructor, parsed, garguile_root),
+        Fields::Unit => quote! { #constructor },
+    }
+}
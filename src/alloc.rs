@@ -16,12 +16,18 @@
 //! Implementations of [Allocator].
 
 use {
-    crate::{Guile, sys::scm_gc_malloc},
-    allocator_api2::alloc::{AllocError, Allocator, Layout},
+    crate::{
+        Guile,
+        sys::{GC_register_finalizer_no_order, scm_gc_malloc},
+    },
+    allocator_api2::{
+        alloc::{AllocError, Allocator, Layout},
+        boxed::Box,
+    },
     std::{
         ffi::{CStr, c_void},
         marker::PhantomData,
-        ptr::NonNull,
+        ptr::{self, NonNull},
     },
 };
 
@@ -47,10 +53,19 @@ unsafe impl Allocator for CAllocator {
     }
 }
 
+/// A GC finalizer callback, matching `GC_finalization_proc`: `(object, client_data)`.
+type Finalizer = unsafe extern "C" fn(*mut c_void, *mut c_void);
+
 /// Allocator that uses the guile garbage collector.
+///
+/// [Self::deallocate] is a no-op because the collector reclaims the memory on its own schedule;
+/// this means any block holding a type with a non-trivial [Drop] leaks its owned resources
+/// unless a finalizer is registered. See [Self::new_finalized]/[Self::box_finalized] to opt in
+/// to automatic finalization.
 #[derive(Clone, Copy)]
 pub struct GcAllocator<'gm, 'a> {
     purpose: &'a CStr,
+    finalizer: Option<Finalizer>,
     _marker: PhantomData<&'gm ()>,
 }
 impl<'gm, 'a> GcAllocator<'gm, 'a> {
@@ -70,17 +85,100 @@ impl<'gm, 'a> GcAllocator<'gm, 'a> {
     pub fn new(purpose: &'a CStr, _: &'gm Guile) -> Self {
         Self {
             purpose,
+            finalizer: None,
             _marker: PhantomData,
         }
     }
+
+    /// Create an allocator that registers `finalizer` against every block it allocates, so the
+    /// collector runs it instead of silently leaking whatever the block owns.
+    ///
+    /// # Safety
+    ///
+    /// `finalizer` must be safe to invoke with a pointer to a block of the layout this
+    /// allocator will be used to allocate; see [Self::box_finalized] for the common case of
+    /// dropping a single `T`.
+    ///
+    /// # Warning
+    ///
+    /// Finalizers run on a GC thread, outside of guile mode. They must not call back into
+    /// Guile (construct a [Scm][crate::scm::Scm], allocate through this crate, ...) or the heap
+    /// may be corrupted.
+    pub unsafe fn new_finalized(purpose: &'a CStr, finalizer: Finalizer, _: &'gm Guile) -> Self {
+        Self {
+            purpose,
+            finalizer: Some(finalizer),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Box `value` in GC memory with a finalizer that runs its [Drop] impl when the collector
+    /// reclaims the block, instead of leaking whatever `value` owns.
+    ///
+    /// The returned pointer is not an owning [Box]: ownership of `value` belongs to the
+    /// collector from this call onward, via the registered finalizer. If this returned a `Box`
+    /// instead, dropping it on the Rust side would run `T`'s destructor immediately, and the
+    /// finalizer would run it a second time once the collector later reclaimed the
+    /// now-unreferenced block. Callers must not reconstruct an owning `Box` from this pointer
+    /// while the finalizer is still registered against it.
+    ///
+    /// # Safety
+    ///
+    /// See the warning on [Self::new_finalized]: `T`'s [Drop] impl must not re-enter Guile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{alloc::GcAllocator, gc, with_guile};
+    /// # use std::sync::atomic::{self, AtomicBool};
+    /// static DROPPED: AtomicBool = AtomicBool::new(false);
+    /// struct Loud;
+    /// impl Drop for Loud {
+    ///     fn drop(&mut self) {
+    ///         DROPPED.store(true, atomic::Ordering::Release);
+    ///     }
+    /// }
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| unsafe {
+    ///     GcAllocator::box_finalized(Loud, c"loud", guile);
+    ///     // The pointer above is now unreachable from Rust; only the collector can drop `Loud`.
+    ///     gc::force(guile);
+    /// }).unwrap();
+    /// # #[cfg(not(miri))]
+    /// assert!(DROPPED.load(atomic::Ordering::Acquire));
+    /// ```
+    pub unsafe fn box_finalized<T>(value: T, purpose: &'a CStr, guile: &'gm Guile) -> NonNull<T> {
+        unsafe extern "C" fn drop_glue<T>(obj: *mut c_void, _client_data: *mut c_void) {
+            unsafe {
+                ptr::drop_in_place(obj.cast::<T>());
+            }
+        }
+
+        let allocator = unsafe { Self::new_finalized(purpose, drop_glue::<T>, guile) };
+        let boxed = Box::new_in(value, allocator);
+        // Leak rather than drop: the finalizer registered above now solely owns `value`'s
+        // destructor, so letting this `Box` drop here would run it a second time.
+        unsafe { NonNull::new_unchecked(Box::into_raw(boxed)) }
+    }
 }
 unsafe impl Allocator for GcAllocator<'_, '_> {
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
         let size = layout.size();
 
-        NonNull::new(unsafe { scm_gc_malloc(size, self.purpose.as_ptr()) }.cast::<u8>())
-            .map(|ptr| NonNull::slice_from_raw_parts(ptr, size))
-            .ok_or(AllocError)
+        let ptr = NonNull::new(unsafe { scm_gc_malloc(size, self.purpose.as_ptr()) }.cast::<u8>())
+            .ok_or(AllocError)?;
+        if let Some(finalizer) = self.finalizer {
+            unsafe {
+                GC_register_finalizer_no_order(
+                    ptr.as_ptr().cast(),
+                    Some(finalizer),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                );
+            }
+        }
+        Ok(NonNull::slice_from_raw_parts(ptr, size))
     }
     unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {}
 }
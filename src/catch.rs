@@ -22,9 +22,12 @@ use {
         reference::ReprScm,
         scm::{Scm, TryFromScm},
         symbol::Symbol,
-        sys::{SCM, SCM_BOOL_T, SCM_UNDEFINED, scm_internal_catch, scm_throw},
+        sys::{SCM, SCM_BOOL_T, SCM_UNDEFINED, scm_cons, scm_internal_catch, scm_throw},
+    },
+    std::{
+        ffi::c_void,
+        panic::{self, AssertUnwindSafe},
     },
-    std::ffi::c_void,
 };
 
 /// Tag for the type of error that you would like to catch.
@@ -143,4 +146,78 @@ impl Guile {
                 "`scm_internal_catch` should be calling either callbacks with non null pointers",
             )
     }
+
+    /// Run `body`, catching any Scheme condition it throws and packaging it as a single
+    /// [Scm] value (the `(key . args)` pair), rather than the split key/args pair
+    /// [Self::try_catch] hands to its handler.
+    ///
+    /// This is the cross-cutting helper other fallible wrappers in the crate (hash tables,
+    /// `eval`, ...) build on, so they don't need to hand-roll their own `scm_internal_catch`
+    /// plumbing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::list::List, symbol::Symbol, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(guile.catch_scm(|_| {}).is_ok());
+    ///     assert!(guile.catch_scm(|guile| guile.throw(Symbol::from_str("foo", guile), List::<i32>::new(guile))).is_err());
+    /// }).unwrap();
+    /// ```
+    pub fn catch_scm<'gm, B, T>(&'gm self, body: B) -> Result<T, Scm<'gm>>
+    where
+        B: FnOnce(&'gm Self) -> T,
+    {
+        self.try_catch(Tag::All, body, |guile, key, args| {
+            Scm::from_ptr(unsafe { scm_cons(key.as_ptr(), args.as_ptr()) }, guile)
+        })
+    }
+
+    /// Run `body`, catching any Scheme condition it throws as a [GuileException] instead of
+    /// unwinding past the caller, so callers get ordinary `?`-based propagation over Guile
+    /// conditions.
+    ///
+    /// A Rust panic inside `body` is itself caught (via [`panic::catch_unwind`]) before it can
+    /// unwind through the `scm_internal_catch` C frame underneath [Self::try_catch], and is
+    /// resumed once control is safely back on the Rust side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::list::List, symbol::Symbol, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(guile.catch(|_| {}).is_ok());
+    ///
+    ///     let err = guile
+    ///         .catch(|guile| guile.throw(Symbol::from_str("foo", guile), List::<i32>::new(guile)))
+    ///         .unwrap_err();
+    ///     assert!(err.args.is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn catch<'gm, B, T>(&'gm self, body: B) -> Result<T, GuileException<'gm>>
+    where
+        B: FnOnce(&'gm Self) -> T,
+    {
+        let result = self.try_catch(
+            Tag::All,
+            |guile| panic::catch_unwind(AssertUnwindSafe(|| body(guile))),
+            |_, key, args| GuileException { key, args },
+        );
+
+        match result {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(payload)) => panic::resume_unwind(payload),
+            Err(exception) => Err(exception),
+        }
+    }
+}
+
+/// The Scheme condition [`Guile::catch`] caught: the symbol naming the condition's type, along
+/// with whatever irritant arguments its `throw` call supplied.
+#[derive(Debug)]
+pub struct GuileException<'gm> {
+    pub key: Symbol<'gm>,
+    pub args: List<'gm, Scm<'gm>>,
 }
@@ -0,0 +1,420 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Association lists: lists of `(key . val)` pairs, looked up with `assq`/`assv`/`assoc`.
+
+use {
+    crate::{
+        Guile,
+        collections::{
+            hash_map::{Eq, Equal, Eqv},
+            list::{self, List},
+            pair::Pair,
+        },
+        reference::{Ref, RefMut, ReprScm},
+        scm::{Scm, ToScm, TryFromScm},
+        sys::{
+            SCM, SCM_EOL, scm_acons, scm_assoc, scm_assq, scm_assv, scm_caar, scm_cdar, scm_cdr,
+            scm_del_assoc_x, scm_del_assq_x, scm_del_assv_x, scm_list_p, scm_set_cdr_x,
+        },
+        type_name::{TypeName, TypeNameBuilder},
+        utils::scm_predicate,
+    },
+    std::{iter::FusedIterator, marker::PhantomData},
+};
+
+/// Association-list vtable, dispatching the lookup/removal strategy to use on keys.
+///
+/// Shares the [`Eq`]/[`Eqv`]/[`Equal`] marker types with
+/// [`hash_map`][crate::collections::hash_map], since they name the exact same three Guile
+/// key-equality strategies.
+trait ScmAssoc {
+    /// Look `key` up in `alist`, returning its `(key . val)` pair or `#f`.
+    const ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM;
+    /// Return `alist` with every pair whose key matches `key` removed.
+    const DEL_ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM;
+}
+impl ScmAssoc for Eq {
+    const ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM = scm_assq;
+    const DEL_ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM = scm_del_assq_x;
+}
+impl ScmAssoc for Eqv {
+    const ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM = scm_assv;
+    const DEL_ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM = scm_del_assv_x;
+}
+impl ScmAssoc for Equal {
+    const ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM = scm_assoc;
+    const DEL_ASSOC: unsafe extern "C" fn(_key: SCM, _alist: SCM) -> SCM = scm_del_assoc_x;
+}
+
+/// An association list usable in scheme.
+#[repr(transparent)]
+pub struct AListInner<'gm, K, V, E>
+where
+    E: ScmAssoc,
+{
+    scm: Scm<'gm>,
+    _marker: PhantomData<(K, V, E)>,
+}
+impl<'gm, K, V, E> AListInner<'gm, K, V, E>
+where
+    E: ScmAssoc,
+{
+    /// Create an empty association list.
+    pub fn new(guile: &'gm Guile) -> Self {
+        Self {
+            scm: Scm::from_ptr(unsafe { SCM_EOL }, guile),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Look `key` up, returning a reference to its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::alist::AList, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut al = AList::new(guile);
+    ///     assert!(al.get(0).is_none());
+    ///     al.insert(0, true);
+    ///     assert_eq!(al.get(0).map(Ref::copied), Some(true));
+    /// }).unwrap();
+    /// ```
+    pub fn get<'a>(&'a self, key: K) -> Option<Ref<'a, 'gm, V>>
+    where
+        K: ToScm<'gm>,
+        V: TryFromScm<'gm> + 'gm,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let pair = unsafe { E::ASSOC(key.to_scm(guile).as_ptr(), self.scm.as_ptr()) };
+        if Scm::from_ptr(pair, guile).is_false() {
+            None
+        } else {
+            Some(unsafe { Ref::new_unchecked(scm_cdr(pair)) })
+        }
+    }
+
+    /// Look `key` up, returning a mutable reference to its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::{alist::AList, pair::Pair}, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut al = AList::new(guile);
+    ///     al.insert(0, Pair::new(1, 1, guile));
+    ///     al.get_mut(0).unwrap().set_car(2);
+    ///     assert_eq!(al.get(0).unwrap().as_car().copied(), 2);
+    /// }).unwrap();
+    /// ```
+    pub fn get_mut<'a>(&'a mut self, key: K) -> Option<RefMut<'a, 'gm, V>>
+    where
+        K: ToScm<'gm>,
+        V: TryFromScm<'gm> + 'gm,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let pair = unsafe { E::ASSOC(key.to_scm(guile).as_ptr(), self.scm.as_ptr()) };
+        if Scm::from_ptr(pair, guile).is_false() {
+            None
+        } else {
+            Some(unsafe { RefMut::with_writer(scm_cdr(pair), write_cdr, pair, 0) })
+        }
+    }
+
+    /// Cons a fresh `(key . val)` pair onto the front of the list.
+    ///
+    /// An existing entry for `key` is shadowed rather than overwritten, matching `acons`'s
+    /// semantics: [Self::get] will find the new pair first, but the old one is still in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::alist::AList, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut al = AList::new(guile);
+    ///     al.insert(0, true);
+    ///     assert_eq!(al.get(0).map(Ref::copied), Some(true));
+    /// }).unwrap();
+    /// ```
+    pub fn insert(&mut self, key: K, val: V)
+    where
+        K: ToScm<'gm>,
+        V: ToScm<'gm>,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let key = key.to_scm(guile).as_ptr();
+        let val = val.to_scm(guile).as_ptr();
+        self.scm = Scm::from_ptr(unsafe { scm_acons(key, val, self.scm.as_ptr()) }, guile);
+    }
+
+    /// Remove every entry for `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::alist::AList, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut al = AList::new(guile);
+    ///     al.insert(0, true);
+    ///     al.remove(0);
+    ///     assert!(al.get(0).is_none());
+    /// }).unwrap();
+    /// ```
+    pub fn remove(&mut self, key: K)
+    where
+        K: ToScm<'gm>,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let key = key.to_scm(guile).as_ptr();
+        self.scm = Scm::from_ptr(unsafe { E::DEL_ASSOC(key, self.scm.as_ptr()) }, guile);
+    }
+
+    /// Iterate over every `(key, val)` entry, in list order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::alist::AList, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut al = AList::new(guile);
+    ///     al.insert(0, true);
+    ///     assert_eq!(
+    ///         al.entries().map(|(k, v)| (k.copied(), v.copied())).collect::<Vec<_>>(),
+    ///         vec![(0, true)],
+    ///     );
+    /// }).unwrap();
+    /// ```
+    pub fn entries<'a>(&'a self) -> Entries<'a, 'gm, K, V> {
+        Entries {
+            car: self.scm.as_ptr(),
+            _marker: PhantomData,
+        }
+    }
+}
+unsafe fn write_cdr(owner: SCM, _: usize, value: SCM) {
+    unsafe { scm_set_cdr_x(owner, value) }
+}
+unsafe impl<K, V, E> ReprScm for AListInner<'_, K, V, E> where E: ScmAssoc {}
+impl<'gm, K, V, E> ToScm<'gm> for AListInner<'gm, K, V, E>
+where
+    E: ScmAssoc,
+{
+    fn to_scm(self, _: &'gm Guile) -> Scm<'gm> {
+        self.scm
+    }
+}
+impl<'gm, K, V, E> TryFromScm<'gm> for AListInner<'gm, K, V, E>
+where
+    K: TryFromScm<'gm>,
+    V: TryFromScm<'gm>,
+    E: ScmAssoc,
+{
+    fn type_name() -> TypeName {
+        let mut builder = TypeNameBuilder::new();
+        builder
+            .push(b"(alist ")
+            .push(K::type_name().to_bytes())
+            .push(b" . ")
+            .push(V::type_name().to_bytes())
+            .push(b")");
+        builder.finish()
+    }
+
+    fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
+        scm_predicate(unsafe { scm_list_p(scm.as_ptr()) }) && {
+            let raw = unsafe {
+                <List<Scm> as TryFromScm>::from_scm_unchecked(scm.copy_unchecked(), guile)
+            };
+            raw.into_iter()
+                .all(|item| Pair::<K, V>::predicate(&item, guile))
+        }
+    }
+
+    unsafe fn from_scm_unchecked(scm: Scm<'gm>, _: &'gm Guile) -> Self {
+        Self {
+            scm,
+            _marker: PhantomData,
+        }
+    }
+}
+impl<'gm, K, V, E> From<AListInner<'gm, K, V, E>> for List<'gm, Pair<'gm, K, V>>
+where
+    K: TryFromScm<'gm>,
+    V: TryFromScm<'gm>,
+    E: ScmAssoc,
+{
+    /// An alist is already, bit-for-bit, a list of `(key . val)` pairs, so this is a free
+    /// reinterpretation rather than a walk-and-rebuild.
+    fn from(alist: AListInner<'gm, K, V, E>) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        unsafe { <Self as TryFromScm>::from_scm_unchecked(alist.scm, guile) }
+    }
+}
+impl<'gm, K, V, E> IntoIterator for AListInner<'gm, K, V, E>
+where
+    K: TryFromScm<'gm>,
+    V: TryFromScm<'gm>,
+    E: ScmAssoc,
+{
+    type Item = (K, V);
+    type IntoIter = IntoIter<'gm, K, V>;
+
+    /// Consumes the alist, yielding owned `(key, val)` tuples via [`Pair::to_tuple`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::alist::AList, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut al = AList::new(guile);
+    ///     al.insert(1, 'b');
+    ///     al.insert(0, 'a');
+    ///     assert_eq!(al.into_iter().collect::<Vec<_>>(), vec![(0, 'a'), (1, 'b')]);
+    /// }).unwrap();
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: List::<Pair<K, V>>::from(self).into_iter(),
+        }
+    }
+}
+
+/// Association list that uses `equal?` for key comparison.
+pub type AList<'gm, K, V> = AListInner<'gm, K, V, Equal>;
+/// Association list that uses `eq?` for key comparison.
+pub type AListQ<'gm, K, V> = AListInner<'gm, K, V, Eq>;
+/// Association list that uses `eqv?` for key comparison.
+pub type AListV<'gm, K, V> = AListInner<'gm, K, V, Eqv>;
+
+/// Iterator over every `(key, val)` entry of an [AListInner]. See [AListInner::entries].
+#[derive(Clone, Copy)]
+pub struct Entries<'a, 'gm, K, V> {
+    car: SCM,
+    _marker: PhantomData<&'a &'gm (K, V)>,
+}
+impl<K, V> FusedIterator for Entries<'_, '_, K, V> {}
+impl<'a, 'gm, K, V> Iterator for Entries<'a, 'gm, K, V> {
+    type Item = (Ref<'a, 'gm, K>, Ref<'a, 'gm, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if unsafe { Scm::from_ptr_unchecked(self.car) }.is_eol() {
+            None
+        } else {
+            let key = unsafe { scm_caar(self.car) };
+            let val = unsafe { scm_cdar(self.car) };
+            self.car = unsafe { scm_cdr(self.car) };
+
+            Some(unsafe { (Ref::new_unchecked(key), Ref::new_unchecked(val)) })
+        }
+    }
+}
+
+/// Iterator over every owned `(key, val)` entry of an [AListInner]. See
+/// [`AListInner::into_iter`][IntoIterator::into_iter].
+pub struct IntoIter<'gm, K, V> {
+    inner: list::IntoIter<'gm, Pair<'gm, K, V>>,
+}
+impl<'gm, K, V> ExactSizeIterator for IntoIter<'gm, K, V>
+where
+    K: TryFromScm<'gm>,
+    V: TryFromScm<'gm>,
+{
+}
+impl<'gm, K, V> FusedIterator for IntoIter<'gm, K, V>
+where
+    K: TryFromScm<'gm>,
+    V: TryFromScm<'gm>,
+{
+}
+impl<'gm, K, V> DoubleEndedIterator for IntoIter<'gm, K, V>
+where
+    K: TryFromScm<'gm>,
+    V: TryFromScm<'gm>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Pair::to_tuple)
+    }
+}
+impl<'gm, K, V> Iterator for IntoIter<'gm, K, V>
+where
+    K: TryFromScm<'gm>,
+    V: TryFromScm<'gm>,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Pair::to_tuple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::with_guile};
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn alist_construction() {
+        with_guile(|guile| {
+            let mut al = AList::new(guile);
+            assert!(al.get(0).is_none());
+            al.insert(0, true);
+            assert_eq!(al.get(0).map(Ref::copied), Some(true));
+
+            al.remove(0);
+            assert!(al.get(0).is_none());
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn alist_entries() {
+        with_guile(|guile| {
+            let mut al = AList::new(guile);
+            al.insert(1, 'b');
+            al.insert(0, 'a');
+            assert_eq!(
+                al.entries()
+                    .map(|(k, v)| (k.copied(), v.copied()))
+                    .collect::<Vec<_>>(),
+                vec![(0, 'a'), (1, 'b')],
+            );
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn alist_into_iter() {
+        with_guile(|guile| {
+            let mut al = AList::new(guile);
+            al.insert(1, 'b');
+            al.insert(0, 'a');
+            assert_eq!(al.into_iter().collect::<Vec<_>>(), vec![(0, 'a'), (1, 'b')]);
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,355 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE.
+
+//! General n-dimensional arrays (`make-array`, `make-shared-array`, ...), the rank-generic
+//! counterpart to [Vector][crate::collections::vector::Vector]'s rank-1 elements.
+
+use {
+    crate::{
+        Guile,
+        reference::{Ref, ReprScm},
+        scm::{Scm, ToScm, TryFromScm},
+        sys::{
+            SCM, SCM_BOOL_F, scm_array_get_handle, scm_array_handle_dims,
+            scm_array_handle_elements, scm_array_handle_rank, scm_array_handle_release,
+            scm_array_handle_writable_elements, scm_array_p, scm_t_array_handle,
+        },
+        type_name::TypeName,
+        utils::scm_predicate,
+    },
+    std::{iter::FusedIterator, marker::PhantomData},
+};
+
+/// One axis of an [Array]: the inclusive bounds Guile reports for it, and the stride (in
+/// elements) separating consecutive indices along it.
+#[derive(Clone, Copy, Debug)]
+pub struct Dim {
+    lower: isize,
+    upper: isize,
+    stride: isize,
+}
+impl Dim {
+    /// The number of valid indices along this axis.
+    pub fn len(&self) -> usize {
+        usize::try_from(self.upper - self.lower + 1).unwrap_or(0)
+    }
+
+    /// Whether this axis has no valid indices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The smallest valid index along this axis.
+    pub fn lower(&self) -> isize {
+        self.lower
+    }
+
+    /// The largest valid index along this axis.
+    pub fn upper(&self) -> isize {
+        self.upper
+    }
+
+    /// The stride, in elements, separating consecutive indices along this axis.
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+}
+
+/// Opens a handle on `scm` just long enough to copy out its dimensions, releasing it before
+/// returning; [Array] caches the result instead of reopening a handle on every shape query.
+fn read_dims(scm: SCM) -> Box<[Dim]> {
+    let mut handle = scm_t_array_handle::default();
+    unsafe {
+        scm_array_get_handle(scm, &raw mut handle);
+    }
+    let rank = unsafe { scm_array_handle_rank(&raw mut handle) };
+    let dims = unsafe { scm_array_handle_dims(&raw mut handle) };
+    let result = (0..rank)
+        .map(|i| {
+            let dim = unsafe { &*dims.add(i) };
+            Dim {
+                lower: dim.lbnd,
+                upper: dim.ubnd,
+                stride: dim.inc,
+            }
+        })
+        .collect();
+    unsafe {
+        scm_array_handle_release(&raw mut handle);
+    }
+    result
+}
+
+/// The flat offset, in elements from the handle's base pointer, of `indices`, or `None` if any
+/// component is out of bounds for its axis.
+fn offset_of(dims: &[Dim], indices: &[isize]) -> Option<isize> {
+    (dims.len() == indices.len()).then_some(())?;
+    dims.iter()
+        .zip(indices)
+        .try_fold(0isize, |offset, (dim, &i)| {
+            (dim.lower..=dim.upper)
+                .contains(&i)
+                .then(|| offset + (i - dim.lower) * dim.stride)
+        })
+}
+
+/// A general Guile array of any rank, caching its shape (read once via `scm_array_get_handle`)
+/// so [Self::shape] and the bounds checks in [Self::get]/[Self::set] don't need to reopen a
+/// handle just to ask Guile how big the array is.
+pub struct Array<'gm, T> {
+    scm: Scm<'gm>,
+    dims: Box<[Dim]>,
+    _marker: PhantomData<T>,
+}
+impl<'gm, T> Array<'gm, T> {
+    /// The number of axes.
+    pub fn rank(&self) -> usize {
+        self.dims.len()
+    }
+
+    /// The axes making up this array's shape, outermost first.
+    pub fn shape(&self) -> &[Dim] {
+        &self.dims
+    }
+
+    /// Get the element at `indices`, one per axis, or `None` if `indices` has the wrong length or
+    /// any component is out of bounds for its axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::array::Array, reference::Ref, string::String, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let array = unsafe { guile.eval::<Array<i32>>(&String::from_str("(make-array 0 2 2)", guile)) }.unwrap();
+    ///     assert_eq!(array.get(&[0, 0]).map(Ref::copied), Some(0));
+    ///     assert!(array.get(&[2, 0]).is_none());
+    /// }).unwrap();
+    /// ```
+    pub fn get<'a>(&'a self, indices: &[isize]) -> Option<Ref<'a, 'gm, T>>
+    where
+        T: TryFromScm<'gm>,
+    {
+        let offset = offset_of(&self.dims, indices)?;
+
+        let mut handle = scm_t_array_handle::default();
+        let ptr = unsafe {
+            scm_array_get_handle(self.scm.as_ptr(), &raw mut handle);
+            scm_array_handle_elements(&raw mut handle)
+        };
+        let elem = unsafe { Ref::new_unchecked(ptr.offset(offset).read()) };
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+        Some(elem)
+    }
+
+    /// Set the element at `indices`, one per axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` has the wrong length or any component is out of bounds for its axis.
+    pub fn set<V>(&mut self, indices: &[isize], value: V)
+    where
+        T: ToScm<'gm> + TryFromScm<'gm>,
+        V: ToScm<'gm>,
+    {
+        let offset = offset_of(&self.dims, indices).unwrap_or_else(|| {
+            panic!("index out of bounds: {indices:?} is not in {:?}", self.dims)
+        });
+
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let value = value.to_scm(guile).as_ptr();
+
+        let mut handle = scm_t_array_handle::default();
+        unsafe {
+            scm_array_get_handle(self.scm.as_ptr(), &raw mut handle);
+            scm_array_handle_writable_elements(&raw mut handle)
+                .offset(offset)
+                .write(value);
+            scm_array_handle_release(&raw mut handle);
+        }
+    }
+
+    /// A view over the same backing storage with axes `permutation` reordered, touching no
+    /// memory: only the cached strides and bounds are reordered, exactly as Guile's own
+    /// `make-shared-array` transposition would be implemented.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `permutation` is not exactly a permutation of `0..self.rank()`.
+    pub fn transpose(&self, permutation: &[usize]) -> Self {
+        let mut seen = vec![false; self.dims.len()];
+        assert!(
+            permutation.len() == self.dims.len()
+                && permutation
+                    .iter()
+                    .all(|&axis| axis < seen.len() && !std::mem::replace(&mut seen[axis], true)),
+            "permutation must name every axis exactly once"
+        );
+        let dims = permutation.iter().map(|&axis| self.dims[axis]).collect();
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self {
+            scm: Scm::from_ptr(self.scm.as_ptr(), guile),
+            dims,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Iterate the elements along `axis`, holding every other axis at its lower bound.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis >= self.rank()`.
+    pub fn iter_axis<'a>(&'a self, axis: usize) -> AxisIter<'a, 'gm, T>
+    where
+        T: TryFromScm<'gm>,
+    {
+        let dim = self.dims[axis];
+
+        let mut handle = scm_t_array_handle::default();
+        // The handle's base pointer already corresponds to every axis sitting at its lower
+        // bound, so no additional offset is needed before striding along `axis`.
+        let ptr = unsafe {
+            scm_array_get_handle(self.scm.as_ptr(), &raw mut handle);
+            scm_array_handle_elements(&raw mut handle)
+        };
+
+        AxisIter {
+            handle,
+            ptr,
+            stride: dim.stride,
+            remaining: dim.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+unsafe impl<'gm, T> ReprScm for Array<'gm, T> {}
+impl<'gm, T> ToScm<'gm> for Array<'gm, T> {
+    fn to_scm(self, _: &'gm Guile) -> Scm<'gm> {
+        self.scm
+    }
+}
+impl<'gm, T> TryFromScm<'gm> for Array<'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"array")
+    }
+
+    fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
+        scm_predicate(unsafe { scm_array_p(scm.as_ptr(), SCM_BOOL_F) })
+    }
+
+    unsafe fn from_scm_unchecked(scm: Scm<'gm>, _: &'gm Guile) -> Self {
+        let dims = read_dims(scm.as_ptr());
+        Self {
+            scm,
+            dims,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Iterator for [Array::iter_axis].
+pub struct AxisIter<'a, 'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    handle: scm_t_array_handle,
+    ptr: *const SCM,
+    stride: isize,
+    remaining: usize,
+    _marker: PhantomData<&'a &'gm T>,
+}
+impl<'gm, T> Drop for AxisIter<'_, 'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    fn drop(&mut self) {
+        unsafe {
+            scm_array_handle_release(&raw mut self.handle);
+        }
+    }
+}
+impl<'a, 'gm, T> Iterator for AxisIter<'a, 'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    type Item = Ref<'a, 'gm, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let elem = unsafe { Ref::new_unchecked(self.ptr.read()) };
+        self.ptr = unsafe { self.ptr.offset(self.stride) };
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<'gm, T> ExactSizeIterator for AxisIter<'_, 'gm, T> where T: TryFromScm<'gm> {}
+impl<'gm, T> FusedIterator for AxisIter<'_, 'gm, T> where T: TryFromScm<'gm> {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{string::String, with_guile},
+    };
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn array_get_set() {
+        with_guile(|guile| {
+            let mut array =
+                unsafe { guile.eval::<Array<i32>>(&String::from_str("(make-array 0 2 2)", guile)) }
+                    .unwrap();
+            assert_eq!(array.rank(), 2);
+            assert_eq!(array.get(&[0, 0]).map(Ref::copied), Some(0));
+            array.set(&[1, 1], 5);
+            assert_eq!(array.get(&[1, 1]).map(Ref::copied), Some(5));
+            assert!(array.get(&[2, 0]).is_none());
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn array_transpose() {
+        with_guile(|guile| {
+            let mut array =
+                unsafe { guile.eval::<Array<i32>>(&String::from_str("(make-array 0 2 3)", guile)) }
+                    .unwrap();
+            array.set(&[0, 1], 7);
+            let transposed = array.transpose(&[1, 0]);
+            assert_eq!(transposed.shape()[0].len(), 3);
+            assert_eq!(transposed.shape()[1].len(), 2);
+            assert_eq!(transposed.get(&[1, 0]).map(Ref::copied), Some(7));
+        })
+        .unwrap();
+    }
+}
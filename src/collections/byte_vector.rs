@@ -25,15 +25,33 @@ use {
         Guile,
         alloc::CAllocator,
         collections::list::List,
+        dynwind::Dynwind,
         reference::ReprScm,
         scm::{Scm, ToScm, TryFromScm},
         sys::{SCM, scm_array_handle_release, scm_t_array_handle},
+        type_name::TypeName,
         utils::scm_predicate,
+        valgrind,
     },
     allocator_api2::vec::Vec,
-    std::{borrow::Cow, ffi::CStr, iter::FusedIterator, marker::PhantomData, num::NonZeroUsize},
+    std::{
+        ffi::CStr,
+        iter::FusedIterator,
+        marker::PhantomData,
+        mem,
+        num::NonZeroUsize,
+        ops::{Deref, DerefMut, Index, IndexMut},
+        pin::Pin,
+        ptr, slice,
+    },
 };
 
+/// The byte extent of `len` elements spaced `step` elements apart, for annotating the memory an
+/// array handle exposes to [valgrind].
+fn mem_extent<T>(len: usize, step: isize) -> usize {
+    len * step.unsigned_abs() * mem::size_of::<T>()
+}
+
 pub(crate) trait ByteVectorType {
     const VECTOR_TYPE_NAME: &CStr;
     const FROM_LIST: unsafe extern "C" fn(_: SCM) -> SCM;
@@ -41,6 +59,8 @@ pub(crate) trait ByteVectorType {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -63,6 +83,8 @@ impl ByteVectorType for u8 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_u8vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_u8vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -86,6 +108,8 @@ impl ByteVectorType for u16 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_u16vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_u16vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -109,6 +133,8 @@ impl ByteVectorType for u32 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_u32vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_u32vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -132,6 +158,8 @@ impl ByteVectorType for u64 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_u64vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_u64vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -155,6 +183,8 @@ impl ByteVectorType for i8 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_s8vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_s8vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -178,6 +208,8 @@ impl ByteVectorType for i16 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_s16vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_s16vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -201,6 +233,8 @@ impl ByteVectorType for i32 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_s32vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_s32vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -224,6 +258,8 @@ impl ByteVectorType for i64 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_s64vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_s64vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -247,6 +283,8 @@ impl ByteVectorType for f32 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_f32vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_f32vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -270,6 +308,8 @@ impl ByteVectorType for f64 {
 
     const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_f64vector_p;
 
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_f64vector;
+
     const ELEMENTS: unsafe extern "C" fn(
         _: SCM,
         _: *mut scm_t_array_handle,
@@ -287,6 +327,126 @@ impl ByteVectorType for f64 {
         crate::sys::scm_take_f64vector;
 }
 
+/// Adapts `scm_c32vector_elements`'s `*const f32` to a `*const Complex32`; sound since
+/// [Complex32] is `repr(C)` over the same two `f32`s Guile already lays out contiguously, with
+/// `lenp`/`incp` counted in complex (not float) units.
+unsafe extern "C" fn complex32_elements(
+    vec: SCM,
+    handle: *mut scm_t_array_handle,
+    lenp: *mut usize,
+    incp: *mut isize,
+) -> *const Complex32 {
+    unsafe { crate::sys::scm_c32vector_elements(vec, handle, lenp, incp).cast() }
+}
+/// See [complex32_elements].
+unsafe extern "C" fn complex32_elements_mut(
+    vec: SCM,
+    handle: *mut scm_t_array_handle,
+    lenp: *mut usize,
+    incp: *mut isize,
+) -> *mut Complex32 {
+    unsafe { crate::sys::scm_c32vector_writable_elements(vec, handle, lenp, incp).cast() }
+}
+
+/// A `c32vector` element: two packed IEEE 754 single-precision floats, exactly as
+/// `scm_c32vector_elements` lays them out, so this can serve as both [ByteVectorType]'s backing
+/// pointee and its element type with no separate conversion step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Complex32 {
+    pub real: f32,
+    pub imag: f32,
+}
+impl Complex32 {
+    /// Widen into a [`Complex`][crate::num::Complex].
+    pub fn to_complex<'gm>(self, guile: &'gm Guile) -> crate::num::Complex<'gm> {
+        crate::num::Complex::new(self.real.into(), self.imag.into(), guile)
+    }
+}
+impl ByteVectorType for Complex32 {
+    const VECTOR_TYPE_NAME: &CStr = c"#c32()";
+    const FROM_LIST: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_list_to_c32vector;
+    const TO_LIST: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_c32vector_to_list;
+
+    const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_c32vector_p;
+
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_c32vector;
+
+    const ELEMENTS: unsafe extern "C" fn(
+        _: SCM,
+        _: *mut scm_t_array_handle,
+        _: *mut usize,
+        _: *mut isize,
+    ) -> *const Self = complex32_elements;
+    const ELEMENTS_MUT: unsafe extern "C" fn(
+        _: SCM,
+        _: *mut scm_t_array_handle,
+        _: *mut usize,
+        _: *mut isize,
+    ) -> *mut Self = complex32_elements_mut;
+
+    const TAKE: unsafe extern "C" fn(_: *const Self, _: usize) -> SCM =
+        crate::sys::scm_take_c32vector;
+}
+
+/// See [complex32_elements]; the `f64` analogue for `c64vector`.
+unsafe extern "C" fn complex64_elements(
+    vec: SCM,
+    handle: *mut scm_t_array_handle,
+    lenp: *mut usize,
+    incp: *mut isize,
+) -> *const Complex64 {
+    unsafe { crate::sys::scm_c64vector_elements(vec, handle, lenp, incp).cast() }
+}
+/// See [complex64_elements].
+unsafe extern "C" fn complex64_elements_mut(
+    vec: SCM,
+    handle: *mut scm_t_array_handle,
+    lenp: *mut usize,
+    incp: *mut isize,
+) -> *mut Complex64 {
+    unsafe { crate::sys::scm_c64vector_writable_elements(vec, handle, lenp, incp).cast() }
+}
+
+/// A `c64vector` element: two packed IEEE 754 double-precision floats; see [Complex32].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Complex64 {
+    pub real: f64,
+    pub imag: f64,
+}
+impl Complex64 {
+    /// Widen into a [`Complex`][crate::num::Complex].
+    pub fn to_complex<'gm>(self, guile: &'gm Guile) -> crate::num::Complex<'gm> {
+        crate::num::Complex::new(self.real, self.imag, guile)
+    }
+}
+impl ByteVectorType for Complex64 {
+    const VECTOR_TYPE_NAME: &CStr = c"#c64()";
+    const FROM_LIST: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_list_to_c64vector;
+    const TO_LIST: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_c64vector_to_list;
+
+    const PREDICATE: unsafe extern "C" fn(_: SCM) -> SCM = crate::sys::scm_c64vector_p;
+
+    const MAKE: unsafe extern "C" fn(_: usize) -> SCM = crate::sys::scm_c_make_c64vector;
+
+    const ELEMENTS: unsafe extern "C" fn(
+        _: SCM,
+        _: *mut scm_t_array_handle,
+        _: *mut usize,
+        _: *mut isize,
+    ) -> *const Self = complex64_elements;
+    const ELEMENTS_MUT: unsafe extern "C" fn(
+        _: SCM,
+        _: *mut scm_t_array_handle,
+        _: *mut usize,
+        _: *mut isize,
+    ) -> *mut Self = complex64_elements_mut;
+
+    const TAKE: unsafe extern "C" fn(_: *const Self, _: usize) -> SCM =
+        crate::sys::scm_take_c64vector;
+}
+
 #[repr(transparent)]
 pub struct ByteVector<'gm, T>
 where
@@ -311,6 +471,7 @@ where
                 &raw mut step,
             )
         };
+        valgrind::make_mem_defined(ptr.cast(), mem_extent::<T>(len, step));
 
         Iter {
             handle,
@@ -332,6 +493,7 @@ where
                 &raw mut step,
             )
         };
+        valgrind::make_mem_defined(ptr.cast(), mem_extent::<T>(len, step));
 
         IterMut {
             handle,
@@ -341,7 +503,717 @@ where
             _marker: PhantomData,
         }
     }
+
+    /// Borrow the elements as a contiguous slice, or `None` if the underlying array handle
+    /// reports a step other than `1` (e.g. a shared, strided sub-vector).
+    ///
+    /// The array handle is kept open for as long as the returned [Slice] is alive, and released
+    /// when it's dropped; see [Slice].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::list::List, collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vector = ByteVector::from(List::from_iter([3_u8, 2, 1], guile));
+    ///     assert_eq!(&*vector.as_slice().unwrap(), [1, 2, 3].as_slice());
+    /// }).unwrap();
+    /// ```
+    pub fn as_slice(&self) -> Option<Slice<'_, 'gm, T>> {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        if step == 1 {
+            Some(Slice {
+                handle,
+                ptr,
+                len,
+                _marker: PhantomData,
+            })
+        } else {
+            unsafe {
+                scm_array_handle_release(&raw mut handle);
+            }
+            None
+        }
+    }
+
+    /// See [Self::as_slice].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::list::List, collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut vector = ByteVector::from(List::from_iter([3_u8, 2, 1], guile));
+    ///     vector.as_mut_slice().unwrap().iter_mut().for_each(|i| *i += 1);
+    ///     assert_eq!(&*vector.as_slice().unwrap(), [2, 3, 4].as_slice());
+    /// }).unwrap();
+    /// ```
+    pub fn as_mut_slice(&mut self) -> Option<SliceMut<'_, 'gm, T>> {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS_MUT(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        if step == 1 {
+            Some(SliceMut {
+                handle,
+                ptr,
+                len,
+                _marker: PhantomData,
+            })
+        } else {
+            unsafe {
+                scm_array_handle_release(&raw mut handle);
+            }
+            None
+        }
+    }
+
+    /// Acquire the elements like [Self::as_slice], but also protect the array handle through
+    /// `wind`'s [Dynwind] scope, so it's still released exactly once if this scope is left via a
+    /// non-local exit (a captured continuation, or [`Guile::throw`][crate::Guile::throw]) that
+    /// would otherwise skip straight past [Slice]'s [Drop] impl.
+    ///
+    /// Unlike [Self::as_slice], the returned [Elements] exposes the elements whatever stride
+    /// Guile reports, not only a contiguous run: [`Elements::as_slice`] is still only `Some` when
+    /// the stride is `1`, but [`Elements::iter`] strides through `base.add(i * inc)` regardless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::list::List, collections::byte_vector::ByteVector, dynwind::Dynwind, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vector = ByteVector::from(List::from_iter([3_u8, 2, 1], guile));
+    ///     Dynwind::scope(|wind| {
+    ///         let elements = vector.elements_in(wind);
+    ///         assert_eq!(elements.as_slice(), Some([1, 2, 3].as_slice()));
+    ///         assert_eq!(elements.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    ///     }, guile);
+    /// }).unwrap();
+    /// ```
+    pub fn elements_in<'a>(&'a self, wind: &'a Dynwind<'gm>) -> Elements<'a, 'gm, T> {
+        let mut handle = Box::pin(ArrayHandle(scm_t_array_handle::default()));
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS(
+                self.scm.as_ptr(),
+                &raw mut handle.as_mut().get_unchecked_mut().0,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+        wind.protect(handle.as_mut());
+
+        Elements {
+            handle,
+            ptr,
+            len,
+            step,
+            _marker: PhantomData,
+        }
+    }
+
+    /// See [Self::elements_in]; requiring `&mut self` rules out any other live borrow of this
+    /// vector's elements at the type level.
+    pub fn elements_mut_in<'a>(&'a mut self, wind: &'a Dynwind<'gm>) -> ElementsMut<'a, 'gm, T> {
+        let mut handle = Box::pin(ArrayHandle(scm_t_array_handle::default()));
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS_MUT(
+                self.scm.as_ptr(),
+                &raw mut handle.as_mut().get_unchecked_mut().0,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+        wind.protect(handle.as_mut());
+
+        ElementsMut {
+            handle,
+            ptr,
+            len,
+            step,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Build a uniform vector by copying the elements of `slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vector = ByteVector::<u8>::from_slice(&[1, 2, 3], guile);
+    ///     assert_eq!(&*vector.as_slice().unwrap(), [1, 2, 3].as_slice());
+    /// }).unwrap();
+    /// ```
+    pub fn from_slice(slice: &[T], _: &Guile) -> Self
+    where
+        T: Copy,
+    {
+        let scm = unsafe { T::MAKE(slice.len()) };
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe { T::ELEMENTS_MUT(scm, &raw mut handle, &raw mut len, &raw mut step) };
+
+        if step == 1 {
+            unsafe {
+                ptr::copy_nonoverlapping(slice.as_ptr(), ptr, len);
+            }
+        } else {
+            for (i, &value) in slice.iter().enumerate() {
+                unsafe {
+                    ptr.offset(isize::try_from(i).unwrap() * step).write(value);
+                }
+            }
+        }
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+
+        Self {
+            scm: unsafe { Scm::from_ptr_unchecked(scm) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Build a vector directly from an [ExactSizeIterator], allocating the target vector up
+    /// front and filling it element-by-element, without materializing an intermediate [List] or
+    /// [Vec] first; the mirror image of [Self::to_vec]'s read path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vector = ByteVector::<u8>::from_iter([1, 2, 3], guile);
+    ///     assert_eq!(&*vector.as_slice().unwrap(), [1, 2, 3].as_slice());
+    /// }).unwrap();
+    /// ```
+    pub fn from_iter<I>(iter: I, _: &Guile) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let scm = unsafe { T::MAKE(iter.len()) };
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe { T::ELEMENTS_MUT(scm, &raw mut handle, &raw mut len, &raw mut step) };
+
+        for (i, item) in iter.enumerate() {
+            unsafe {
+                ptr.offset(isize::try_from(i).unwrap() * step).write(item);
+            }
+        }
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+
+        Self {
+            scm: unsafe { Scm::from_ptr_unchecked(scm) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Copy every element out into a freshly allocated [Vec], doing a single
+    /// [`ptr::copy_nonoverlapping`] when the array handle reports a step of `1` instead of
+    /// reading element-by-element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vector = ByteVector::<u8>::from_slice(&[1, 2, 3], guile);
+    ///     assert_eq!(vector.to_vec().as_slice(), [1, 2, 3]);
+    /// }).unwrap();
+    /// ```
+    pub fn to_vec(&self) -> Vec<T, CAllocator>
+    where
+        T: Copy,
+    {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        let mut vec = Vec::with_capacity_in(len, CAllocator);
+        if step == 1 {
+            unsafe {
+                ptr::copy_nonoverlapping(ptr, vec.as_mut_ptr(), len);
+                vec.set_len(len);
+            }
+        } else {
+            for i in 0..len {
+                vec.push(unsafe { ptr.offset(isize::try_from(i).unwrap() * step).read() });
+            }
+        }
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+
+        vec
+    }
+
+    /// Copy every element of `src` into this vector, doing a single
+    /// [`ptr::copy_nonoverlapping`] when the array handle reports a step of `1` instead of
+    /// writing element-by-element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src.len()` differs from this vector's length, matching
+    /// [`<[T]>::copy_from_slice`][slice::copy_from_slice].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut vector = ByteVector::<u8>::from_slice(&[0, 0, 0], guile);
+    ///     vector.copy_from_slice(&[1, 2, 3]);
+    ///     assert_eq!(vector.to_vec().as_slice(), [1, 2, 3]);
+    /// }).unwrap();
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS_MUT(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+        assert_eq!(
+            src.len(),
+            len,
+            "source slice length ({}) does not match destination vector length ({len})",
+            src.len()
+        );
+
+        if step == 1 {
+            unsafe {
+                ptr::copy_nonoverlapping(src.as_ptr(), ptr, len);
+            }
+        } else {
+            for (i, &value) in src.iter().enumerate() {
+                unsafe {
+                    ptr.offset(isize::try_from(i).unwrap() * step).write(value);
+                }
+            }
+        }
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+    }
+
+    /// The number of elements in the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(ByteVector::<u8>::from_slice(&[1, 2, 3], guile).len(), 3);
+    /// }).unwrap();
+    /// ```
+    pub fn len(&self) -> usize {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        unsafe {
+            T::ELEMENTS(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            );
+            scm_array_handle_release(&raw mut handle);
+        }
+        len
+    }
+
+    /// Whether the vector has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(ByteVector::<u8>::from_slice(&[], guile).is_empty());
+    ///     assert!(!ByteVector::<u8>::from_slice(&[1], guile).is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a copy of the element at `i`, respecting the stride Guile reports for shared,
+    /// strided sub-vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vector = ByteVector::<u8>::from_slice(&[1, 2, 3], guile);
+    ///     assert_eq!(vector.get(1), Some(2));
+    ///     assert_eq!(vector.get(3), None);
+    /// }).unwrap();
+    /// ```
+    pub fn get(&self, i: usize) -> Option<T>
+    where
+        T: Copy,
+    {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        let elem =
+            (i < len).then(|| unsafe { ptr.offset(isize::try_from(i).unwrap() * step).read() });
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+        elem
+    }
+
+    /// Get a mutable reference to the element at `i`, respecting the stride Guile reports for
+    /// shared, strided sub-vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::byte_vector::ByteVector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut vector = ByteVector::<u8>::from_slice(&[1, 2, 3], guile);
+    ///     *vector.get_mut(1).unwrap() += 1;
+    ///     assert_eq!(vector.get(1), Some(3));
+    ///     assert!(vector.get_mut(3).is_none());
+    /// }).unwrap();
+    /// ```
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS_MUT(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        let elem =
+            (i < len).then(|| unsafe { &mut *ptr.offset(isize::try_from(i).unwrap() * step) });
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+        elem
+    }
+}
+impl<T> Index<usize> for ByteVector<'_, T>
+where
+    T: Copy + ByteVectorType,
+{
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    fn index(&self, i: usize) -> &T {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+        assert!(
+            i < len,
+            "index out of bounds: the len is {len} but the index is {i}"
+        );
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+            &*ptr.offset(isize::try_from(i).unwrap() * step)
+        }
+    }
+}
+impl<T> IndexMut<usize> for ByteVector<'_, T>
+where
+    T: Copy + ByteVectorType,
+{
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            T::ELEMENTS_MUT(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+        assert!(
+            i < len,
+            "index out of bounds: the len is {len} but the index is {i}"
+        );
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+            &mut *ptr.offset(isize::try_from(i).unwrap() * step)
+        }
+    }
+}
+
+/// A borrowed, contiguous view of a [ByteVector]'s elements.
+///
+/// Owns the array handle backing the slice and releases it (via [scm_array_handle_release]) on
+/// [Drop], so the handle stays open for exactly as long as the borrow is alive.
+pub struct Slice<'a, 'gm, T> {
+    handle: scm_t_array_handle,
+    ptr: *const T,
+    len: usize,
+    _marker: PhantomData<&'a &'gm [T]>,
+}
+impl<T> Drop for Slice<'_, '_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            scm_array_handle_release(&raw mut self.handle);
+        }
+    }
+}
+impl<T> Deref for Slice<'_, '_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+/// A mutably borrowed, contiguous view of a [ByteVector]'s elements; see [Slice].
+pub struct SliceMut<'a, 'gm, T> {
+    handle: scm_t_array_handle,
+    ptr: *mut T,
+    len: usize,
+    _marker: PhantomData<&'a &'gm mut [T]>,
+}
+impl<T> Drop for SliceMut<'_, '_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            scm_array_handle_release(&raw mut self.handle);
+        }
+    }
+}
+impl<T> Deref for SliceMut<'_, '_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+impl<T> DerefMut for SliceMut<'_, '_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+/// Releases its `scm_t_array_handle` on [Drop], same as [Slice]/[SliceMut]; boxed so [Dynwind] can
+/// additionally protect it at a stable address that survives a non-local exit unwinding straight
+/// past whatever Rust frame owns it.
+struct ArrayHandle(scm_t_array_handle);
+impl Drop for ArrayHandle {
+    fn drop(&mut self) {
+        unsafe {
+            scm_array_handle_release(&raw mut self.0);
+        }
+    }
+}
+
+/// A [Dynwind]-protected, strided view of a [ByteVector]'s elements; see
+/// [`ByteVector::elements_in`].
+pub struct Elements<'a, 'gm, T> {
+    handle: Pin<Box<ArrayHandle>>,
+    ptr: *const T,
+    len: usize,
+    step: isize,
+    _marker: PhantomData<&'a &'gm [T]>,
+}
+impl<T> Elements<'_, '_, T> {
+    /// A contiguous view of the elements, or `None` if Guile reports a stride other than `1`
+    /// (e.g. a shared, strided sub-vector); see [`ByteVector::as_slice`].
+    pub fn as_slice(&self) -> Option<&[T]> {
+        (self.step == 1).then(|| unsafe { slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    /// Iterate the elements, respecting whatever stride Guile reports.
+    pub fn iter(&self) -> StridedIter<'_, T> {
+        StridedIter {
+            ptr: self.ptr,
+            step: self.step,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
 }
+
+/// See [Elements]; the mutable counterpart returned by [`ByteVector::elements_mut_in`].
+pub struct ElementsMut<'a, 'gm, T> {
+    handle: Pin<Box<ArrayHandle>>,
+    ptr: *mut T,
+    len: usize,
+    step: isize,
+    _marker: PhantomData<&'a &'gm mut [T]>,
+}
+impl<T> ElementsMut<'_, '_, T> {
+    /// See [Elements::as_slice].
+    pub fn as_slice(&self) -> Option<&[T]> {
+        (self.step == 1).then(|| unsafe { slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    /// See [Elements::as_slice]; the mutable counterpart.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        (self.step == 1).then(|| unsafe { slice::from_raw_parts_mut(self.ptr, self.len) })
+    }
+
+    /// See [Elements::iter].
+    pub fn iter(&self) -> StridedIter<'_, T> {
+        StridedIter {
+            ptr: self.ptr.cast_const(),
+            step: self.step,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// See [Elements::iter]; the mutable counterpart.
+    pub fn iter_mut(&mut self) -> StridedIterMut<'_, T> {
+        StridedIterMut {
+            ptr: self.ptr,
+            step: self.step,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Strides through `base.add(i * inc)`, yielding one element per logical index regardless of
+/// whether they're contiguous; see [Elements::iter].
+pub struct StridedIter<'a, T> {
+    ptr: *const T,
+    step: isize,
+    remaining: usize,
+    _marker: PhantomData<&'a T>,
+}
+impl<T> ExactSizeIterator for StridedIter<'_, T> {}
+impl<T> FusedIterator for StridedIter<'_, T> {}
+impl<'a, T> Iterator for StridedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let elem = unsafe { &*self.ptr };
+        self.ptr = unsafe { self.ptr.offset(self.step) };
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// See [StridedIter]; the mutable counterpart.
+pub struct StridedIterMut<'a, T> {
+    ptr: *mut T,
+    step: isize,
+    remaining: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+impl<T> ExactSizeIterator for StridedIterMut<'_, T> {}
+impl<T> FusedIterator for StridedIterMut<'_, T> {}
+impl<'a, T> Iterator for StridedIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let elem = unsafe { &mut *self.ptr };
+        self.ptr = unsafe { self.ptr.offset(self.step) };
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
 impl<'gm, T> From<List<'gm, T>> for ByteVector<'gm, T>
 where
     T: ByteVectorType,
@@ -385,6 +1257,7 @@ where
                 &raw mut step,
             )
         };
+        valgrind::make_mem_defined(ptr.cast(), mem_extent::<T>(len, step));
 
         IntoIter {
             handle,
@@ -430,8 +1303,8 @@ impl<'gm, T> TryFromScm<'gm> for ByteVector<'gm, T>
 where
     T: ByteVectorType,
 {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(T::VECTOR_TYPE_NAME)
+    fn type_name() -> TypeName {
+        TypeName::from_static(T::VECTOR_TYPE_NAME)
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -461,6 +1334,8 @@ where
     T: ByteVectorType,
 {
     fn drop(&mut self) {
+        let len = self.len.map(NonZeroUsize::get).unwrap_or_default();
+        valgrind::make_mem_noaccess(self.ptr.cast(), mem_extent::<T>(len, self.step));
         unsafe {
             scm_array_handle_release(&raw mut self.handle);
         }
@@ -524,6 +1399,8 @@ where
     T: ByteVectorType,
 {
     fn drop(&mut self) {
+        let len = self.len.map(NonZeroUsize::get).unwrap_or_default();
+        valgrind::make_mem_noaccess(self.ptr.cast(), mem_extent::<T>(len, self.step));
         unsafe {
             scm_array_handle_release(&raw mut self.handle);
         }
@@ -587,6 +1464,11 @@ where
     T: ByteVectorType,
 {
     fn drop(&mut self) {
+        let len = self.len.map(NonZeroUsize::get).unwrap_or_default();
+        valgrind::make_mem_noaccess(
+            self.ptr.cast_const().cast(),
+            mem_extent::<T>(len, self.step),
+        );
         unsafe {
             scm_array_handle_release(&raw mut self.handle);
         }
@@ -0,0 +1,204 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! R6RS bytevectors (`make-bytevector`, `bytevector-u8-ref`, ...), distinct from the SRFI-4
+//! [`NumVector<u8>`][crate::num::NumVector] these happen to share a representation with.
+
+use {
+    crate::{
+        Guile,
+        reference::ReprScm,
+        scm::{Scm, ToScm, TryFromScm},
+        sys::{
+            scm_bytevector_contents, scm_bytevector_p, scm_c_bytevector_length,
+            scm_c_make_bytevector,
+        },
+        type_name::TypeName,
+        utils::scm_predicate,
+    },
+    std::{mem, slice},
+};
+
+/// Byte order for [Bytevector]'s typed scalar accessors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endian {
+    Big,
+    Little,
+    /// The target's own byte order, i.e. [`u32::from_ne_bytes`] and friends.
+    Native,
+}
+
+/// Returned by [Bytevector]'s typed scalar setters when `offset` (plus the value's width)
+/// falls outside the bytevector's contents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange;
+
+/// An R6RS bytevector: a fixed-length, mutable vector of bytes, distinct from a SRFI-4
+/// `u8vector` even though Guile backs both with the same object.
+#[repr(transparent)]
+pub struct Bytevector<'gm> {
+    scm: Scm<'gm>,
+}
+impl<'gm> Bytevector<'gm> {
+    /// Create a bytevector of `len` bytes, all initialized to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::bytevector::Bytevector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(Bytevector::new(3, guile).as_slice(), [0, 0, 0]);
+    /// }).unwrap();
+    /// ```
+    pub fn new(len: usize, _: &'gm Guile) -> Self {
+        Self {
+            scm: unsafe { Scm::from_ptr_unchecked(scm_c_make_bytevector(len)) },
+        }
+    }
+
+    /// The number of bytes in the bytevector.
+    pub fn len(&self) -> usize {
+        unsafe { scm_c_bytevector_length(self.scm.as_ptr()) }
+    }
+
+    /// Whether the bytevector has no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Borrow the bytevector's contents as a byte slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::bytevector::Bytevector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(Bytevector::new(2, guile).as_slice(), [0, 0]);
+    /// }).unwrap();
+    /// ```
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(scm_bytevector_contents(self.scm.as_ptr()), self.len()) }
+    }
+
+    /// See [Self::as_slice].
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        let len = self.len();
+        unsafe { slice::from_raw_parts_mut(scm_bytevector_contents(self.scm.as_ptr()), len) }
+    }
+}
+unsafe impl ReprScm for Bytevector<'_> {}
+impl<'gm> ToScm<'gm> for Bytevector<'gm> {
+    fn to_scm(self, _: &'gm Guile) -> Scm<'gm> {
+        self.scm
+    }
+}
+impl<'gm> TryFromScm<'gm> for Bytevector<'gm> {
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"bytevector")
+    }
+
+    fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
+        scm_predicate(unsafe { scm_bytevector_p(scm.as_ptr()) })
+    }
+
+    unsafe fn from_scm_unchecked(scm: Scm<'gm>, _: &'gm Guile) -> Self {
+        Self { scm }
+    }
+}
+
+/// Generates a pair of bounds-checked, endianness-aware scalar accessors for `$ty`, mirroring
+/// R6RS's `bytevector-$name-ref`/`bytevector-$name-set!`.
+macro_rules! impl_scalar_accessor {
+    ($ref_name:ident, $set_name:ident, $ty:ty) => {
+        impl Bytevector<'_> {
+            #[doc = concat!(
+                "Read a `",
+                stringify!($ty),
+                "` out of the bytes at `offset`, in `endian` byte order, or [None] if those ",
+                "bytes fall outside the contents."
+            )]
+            pub fn $ref_name(&self, offset: usize, endian: Endian) -> Option<$ty> {
+                let bytes = self
+                    .as_slice()
+                    .get(offset..offset + mem::size_of::<$ty>())?
+                    .try_into()
+                    .unwrap();
+
+                Some(match endian {
+                    Endian::Big => <$ty>::from_be_bytes(bytes),
+                    Endian::Little => <$ty>::from_le_bytes(bytes),
+                    Endian::Native => <$ty>::from_ne_bytes(bytes),
+                })
+            }
+
+            #[doc = concat!(
+                "Write `value` as a `",
+                stringify!($ty),
+                "` into the bytes at `offset`, in `endian` byte order.\n\n# Errors\n\nReturns ",
+                "[OutOfRange] instead of writing anything if those bytes fall outside the ",
+                "contents."
+            )]
+            pub fn $set_name(
+                &mut self,
+                offset: usize,
+                value: $ty,
+                endian: Endian,
+            ) -> Result<(), OutOfRange> {
+                let bytes = match endian {
+                    Endian::Big => value.to_be_bytes(),
+                    Endian::Little => value.to_le_bytes(),
+                    Endian::Native => value.to_ne_bytes(),
+                };
+                let dest = self
+                    .as_mut_slice()
+                    .get_mut(offset..offset + mem::size_of::<$ty>())
+                    .ok_or(OutOfRange)?;
+                dest.copy_from_slice(&bytes);
+
+                Ok(())
+            }
+        }
+    };
+}
+impl_scalar_accessor!(u16_ref, u16_set, u16);
+impl_scalar_accessor!(u32_ref, u32_set, u32);
+impl_scalar_accessor!(u64_ref, u64_set, u64);
+impl_scalar_accessor!(s16_ref, s16_set, i16);
+impl_scalar_accessor!(s32_ref, s32_set, i32);
+impl_scalar_accessor!(s64_ref, s64_set, i64);
+impl_scalar_accessor!(f32_ref, f32_set, f32);
+impl_scalar_accessor!(f64_ref, f64_set, f64);
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::with_guile};
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn scalar_accessors_roundtrip() {
+        with_guile(|guile| {
+            let mut bv = Bytevector::new(8, guile);
+            bv.u32_set(0, 0x0102_0304, Endian::Big).unwrap();
+            assert_eq!(bv.as_slice()[..4], [0x01, 0x02, 0x03, 0x04]);
+            assert_eq!(bv.u32_ref(0, Endian::Big), Some(0x0102_0304));
+            assert_eq!(bv.u32_ref(0, Endian::Little), Some(0x0403_0201));
+            assert_eq!(bv.u32_ref(6, Endian::Big), None);
+            assert_eq!(bv.u32_set(6, 0, Endian::Big), Err(OutOfRange));
+        })
+        .unwrap();
+    }
+}
@@ -24,17 +24,28 @@ use {
     crate::{
         Guile,
         collections::list::List,
+        module::Module,
         reference::ReprScm,
         scm::{Scm, ToScm, TryFromScm},
         string::String,
+        symbol::Symbol,
         sys::{
-            SCM_UNDEFINED, scm_char_set_contains_p, scm_char_set_cursor, scm_char_set_cursor_next,
-            scm_char_set_p, scm_char_set_ref, scm_end_of_char_set_p, scm_list_to_char_set,
-            scm_string_to_char_set, scm_to_char_set,
+            SCM, SCM_UNDEFINED, scm_c_make_gsubr, scm_char_set_adjoin, scm_char_set_complement,
+            scm_char_set_contains_p, scm_char_set_cursor, scm_char_set_cursor_next,
+            scm_char_set_delete, scm_char_set_difference, scm_char_set_eq, scm_char_set_filter,
+            scm_char_set_intersection, scm_char_set_leq_p, scm_char_set_p, scm_char_set_ref,
+            scm_char_set_size, scm_char_set_union, scm_end_of_char_set_p, scm_list_to_char_set,
+            scm_string_to_char_set, scm_to_char_set, scm_to_uintptr_t,
         },
+        type_name::TypeName,
         utils::scm_predicate,
     },
-    std::{borrow::Cow, ffi::CStr},
+    std::{
+        cell::Cell,
+        ffi::c_void,
+        ops::{BitAnd, BitOr, Not, RangeInclusive, Sub},
+        ptr,
+    },
 };
 
 /// Character hash sets.
@@ -85,6 +96,458 @@ impl<'gm> CharSet<'gm> {
             char_set: self,
         }
     }
+
+    /// Build a character set out of an arbitrary iterator of characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let abc = CharSet::from_chars(['a', 'b', 'c'], guile);
+    ///     ('a'..='c').for_each(|ch| assert!(abc.contains(ch)));
+    /// }).unwrap();
+    /// ```
+    pub fn from_chars<I>(chars: I, guile: &'gm Guile) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        Self::from(List::from_iter(chars, guile))
+    }
+
+    /// Build a character set out of an inclusive range of characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let abc = CharSet::from_ranges('a'..='c', guile);
+    ///     ('a'..='c').for_each(|ch| assert!(abc.contains(ch)));
+    ///     ('d'..='z').for_each(|ch| assert!(!abc.contains(ch)));
+    /// }).unwrap();
+    /// ```
+    pub fn from_ranges(range: RangeInclusive<char>, guile: &'gm Guile) -> Self {
+        Self::from_chars(range, guile)
+    }
+
+    /// Add `ch` to the set, returning the result as a new set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(CharSet::from_chars(['a', 'b'], guile).adjoin('c').contains('c'));
+    /// }).unwrap();
+    /// ```
+    pub fn adjoin(&self, ch: char) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self(Scm::from_ptr(
+            unsafe { scm_char_set_adjoin(self.0.as_ptr(), crate::list!(guile, ch).as_ptr()) },
+            guile,
+        ))
+    }
+
+    /// Remove `ch` from the set, returning the result as a new set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(!CharSet::from_chars(['a', 'b'], guile).delete('a').contains('a'));
+    /// }).unwrap();
+    /// ```
+    pub fn delete(&self, ch: char) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self(Scm::from_ptr(
+            unsafe { scm_char_set_delete(self.0.as_ptr(), crate::list!(guile, ch).as_ptr()) },
+            guile,
+        ))
+    }
+
+    /// Number of characters in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(CharSet::from_chars(['a', 'b', 'c'], guile).len(), 3);
+    /// }).unwrap();
+    /// ```
+    pub fn len(&self) -> usize {
+        unsafe { scm_to_uintptr_t(scm_char_set_size(self.0.as_ptr())) }
+    }
+
+    /// Check if the set contains no characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(!CharSet::from_chars(['a'], guile).is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check whether every character in `self` is also in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(CharSet::from_chars(['a', 'b'], guile).is_subset(&CharSet::from_chars(['a', 'b', 'c'], guile)));
+    ///     assert!(!CharSet::from_chars(['a', 'b', 'c'], guile).is_subset(&CharSet::from_chars(['a', 'b'], guile)));
+    /// }).unwrap();
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        scm_predicate(unsafe {
+            scm_char_set_leq_p(
+                crate::list!(
+                    guile,
+                    Scm::from_ptr(self.0.as_ptr(), guile),
+                    Scm::from_ptr(other.0.as_ptr(), guile)
+                )
+                .as_ptr(),
+            )
+        })
+    }
+
+    /// Look up one of SRFI-14's predefined character sets by name, e.g. `"char-set:letter"`, from
+    /// the `(srfi srfi-14)` module.
+    fn predefined(name: &str, guile: &'gm Guile) -> Self {
+        let srfi_14 = Module::resolve(&crate::list!(
+            guile,
+            Symbol::from_str("srfi", guile),
+            Symbol::from_str("srfi-14", guile)
+        ))
+        .expect("(srfi srfi-14) should always be resolvable");
+        let binding = srfi_14
+            .read::<Self>(Symbol::from_str(name, guile))
+            .expect("predefined char set should always be defined")
+            .expect("predefined char set binding should always be a char-set");
+        Self(Scm::from_ptr(binding.as_ptr(), guile))
+    }
+
+    /// SRFI-14's `char-set:letter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(CharSet::letter(guile).contains('a'));
+    ///     assert!(!CharSet::letter(guile).contains('1'));
+    /// }).unwrap();
+    /// ```
+    pub fn letter(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:letter", guile)
+    }
+
+    /// SRFI-14's `char-set:digit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(CharSet::digit(guile).contains('1'));
+    ///     assert!(!CharSet::digit(guile).contains('a'));
+    /// }).unwrap();
+    /// ```
+    pub fn digit(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:digit", guile)
+    }
+
+    /// SRFI-14's `char-set:whitespace`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(CharSet::whitespace(guile).contains(' '));
+    /// }).unwrap();
+    /// ```
+    pub fn whitespace(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:whitespace", guile)
+    }
+
+    /// SRFI-14's `char-set:upper-case`.
+    pub fn upper_case(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:upper-case", guile)
+    }
+
+    /// SRFI-14's `char-set:lower-case`.
+    pub fn lower_case(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:lower-case", guile)
+    }
+
+    /// SRFI-14's `char-set:punctuation`.
+    pub fn punctuation(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:punctuation", guile)
+    }
+
+    /// SRFI-14's `char-set:full`, containing every character.
+    pub fn full(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:full", guile)
+    }
+
+    /// SRFI-14's `char-set:empty`, containing no characters.
+    pub fn empty(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:empty", guile)
+    }
+
+    /// SRFI-14's `char-set:letter+digit`.
+    pub fn letter_plus_digit(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:letter+digit", guile)
+    }
+
+    /// SRFI-14's `char-set:graphic`.
+    pub fn graphic(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:graphic", guile)
+    }
+
+    /// SRFI-14's `char-set:printing`.
+    pub fn printing(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:printing", guile)
+    }
+
+    /// SRFI-14's `char-set:blank`.
+    pub fn blank(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:blank", guile)
+    }
+
+    /// SRFI-14's `char-set:iso-control`.
+    pub fn iso_control(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:iso-control", guile)
+    }
+
+    /// SRFI-14's `char-set:symbol`.
+    pub fn symbol(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:symbol", guile)
+    }
+
+    /// SRFI-14's `char-set:hex-digit`.
+    pub fn hex_digit(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:hex-digit", guile)
+    }
+
+    /// SRFI-14's `char-set:ascii`.
+    pub fn ascii(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:ascii", guile)
+    }
+
+    /// SRFI-14's `char-set:designated`.
+    pub fn designated(guile: &'gm Guile) -> Self {
+        Self::predefined("char-set:designated", guile)
+    }
+
+    /// Build a set from every character in `domain` for which `pred` returns `true`, driving
+    /// `scm_char_set_filter` with a freshly-registered gsubr trampoline (see
+    /// [`char_set_filter_callback`]) rather than a cached one, mirroring
+    /// [`HashMapInner::collect_pairs`][crate::collections::hash_map::HashMapInner].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vowels = CharSet::from_predicate_over(&CharSet::letter(guile), |c| "aeiou".contains(c), guile);
+    ///     assert!(vowels.contains('a'));
+    ///     assert!(!vowels.contains('b'));
+    /// }).unwrap();
+    /// ```
+    pub fn from_predicate_over<F>(domain: &Self, mut pred: F, guile: &'gm Guile) -> Self
+    where
+        F: FnMut(char) -> bool,
+    {
+        let callback = unsafe {
+            scm_c_make_gsubr(
+                c"char-set-filter-callback".as_ptr(),
+                1,
+                0,
+                0,
+                char_set_filter_callback::<F> as *mut c_void,
+            )
+        };
+        let previous =
+            FILTER_CALLBACK_DATA.with(|cell| cell.replace((&raw mut pred).cast::<c_void>()));
+        let result = Self(Scm::from_ptr(
+            unsafe { scm_char_set_filter(callback, domain.0.as_ptr(), SCM_UNDEFINED) },
+            guile,
+        ));
+        FILTER_CALLBACK_DATA.with(|cell| cell.set(previous));
+        result
+    }
+
+    /// Like [Self::from_predicate_over], but scanning every character ([Self::full]) instead of a
+    /// supplied domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let digits = CharSet::from_predicate(char::is_numeric, guile);
+    ///     assert!(digits.contains('1'));
+    ///     assert!(!digits.contains('a'));
+    /// }).unwrap();
+    /// ```
+    pub fn from_predicate<F>(pred: F, guile: &'gm Guile) -> Self
+    where
+        F: FnMut(char) -> bool,
+    {
+        Self::from_predicate_over(&Self::full(guile), pred, guile)
+    }
+
+    /// Fold over every character in the set, consuming it; the order is unspecified, as in
+    /// [Self::iter].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(CharSet::from_chars(['a', 'b', 'c'], guile).fold(0, |acc, _| acc + 1), 3);
+    /// }).unwrap();
+    /// ```
+    pub fn fold<B>(self, init: B, f: impl FnMut(B, char) -> B) -> B {
+        self.into_iter().fold(init, f)
+    }
+
+    /// Build a new set by mapping every character in `self` through `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::char_set::CharSet, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let upper = CharSet::from_chars(['a', 'b', 'c'], guile).map(|ch| ch.to_ascii_uppercase());
+    ///     assert!(upper.contains('A'));
+    ///     assert!(!upper.contains('a'));
+    /// }).unwrap();
+    /// ```
+    pub fn map(self, mut f: impl FnMut(char) -> char) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self::from_chars(self.into_iter().map(|ch| f(ch)), guile)
+    }
+}
+thread_local! {
+    /// Smuggles the active [`CharSet::from_predicate_over`] closure past `scm_char_set_filter`'s
+    /// callback, which (unlike `scm_hash_fold`'s) is called with exactly the one argument SRFI-14
+    /// gives a char-set predicate, leaving no argument slot to carry a data pointer through.
+    /// Saved/restored around each call rather than overwritten outright, so a predicate that
+    /// itself builds another set via [`CharSet::from_predicate`] unwinds back to the right value.
+    static FILTER_CALLBACK_DATA: Cell<*mut c_void> = const { Cell::new(ptr::null_mut()) };
+}
+/// Trampoline registered by [`CharSet::from_predicate_over`]; reads the active predicate back out
+/// of [`FILTER_CALLBACK_DATA`].
+extern "C" fn char_set_filter_callback<F>(ch: SCM) -> SCM
+where
+    F: FnMut(char) -> bool,
+{
+    let guile = unsafe { Guile::new_unchecked_ref() };
+    let ch = unsafe { char::from_scm_unchecked(Scm::from_ptr(ch, guile), guile) };
+    let pred = unsafe { &mut *FILTER_CALLBACK_DATA.get().cast::<F>() };
+    pred(ch).to_scm(guile).as_ptr()
+}
+impl<'gm> BitOr for CharSet<'gm> {
+    type Output = Self;
+
+    /// `scm_char_set_union`.
+    fn bitor(self, rhs: Self) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self(Scm::from_ptr(
+            unsafe { scm_char_set_union(crate::list!(guile, self, rhs).as_ptr()) },
+            guile,
+        ))
+    }
+}
+impl<'gm> BitAnd for CharSet<'gm> {
+    type Output = Self;
+
+    /// `scm_char_set_intersection`.
+    fn bitand(self, rhs: Self) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self(Scm::from_ptr(
+            unsafe { scm_char_set_intersection(crate::list!(guile, self, rhs).as_ptr()) },
+            guile,
+        ))
+    }
+}
+impl<'gm> Sub for CharSet<'gm> {
+    type Output = Self;
+
+    /// `scm_char_set_difference`.
+    fn sub(self, rhs: Self) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let lhs = self.0.as_ptr();
+        Self(Scm::from_ptr(
+            unsafe { scm_char_set_difference(lhs, crate::list!(guile, rhs).as_ptr()) },
+            guile,
+        ))
+    }
+}
+impl<'gm> Not for CharSet<'gm> {
+    type Output = Self;
+
+    /// `scm_char_set_complement`.
+    fn not(self) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self(Scm::from_ptr(
+            unsafe { scm_char_set_complement(self.0.as_ptr()) },
+            guile,
+        ))
+    }
+}
+impl PartialEq for CharSet<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        scm_predicate(unsafe {
+            scm_char_set_eq(
+                crate::list!(
+                    guile,
+                    Scm::from_ptr(self.0.as_ptr(), guile),
+                    Scm::from_ptr(other.0.as_ptr(), guile)
+                )
+                .as_ptr(),
+            )
+        })
+    }
+}
+impl<'gm> IntoIterator for CharSet<'gm> {
+    type Item = char;
+    type IntoIter = IntoIter<'gm>;
+
+    /// Consume the set, driving the same cursor protocol as [Self::iter] but without borrowing.
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            cursor: unsafe { Scm::from_ptr_unchecked(scm_char_set_cursor(self.0.as_ptr())) },
+            char_set: self,
+        }
+    }
 }
 impl<'gm> From<char> for CharSet<'gm> {
     fn from(ch: char) -> Self {
@@ -114,8 +577,8 @@ impl<'gm> ToScm<'gm> for CharSet<'gm> {
     }
 }
 impl<'gm> TryFromScm<'gm> for CharSet<'gm> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"char-set")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"char-set")
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -158,6 +621,37 @@ impl Iterator for Iter<'_, '_> {
     }
 }
 
+/// Iterator created by [CharSet::into_iter], owning the set it walks.
+pub struct IntoIter<'gm> {
+    char_set: CharSet<'gm>,
+    cursor: Scm<'gm>,
+}
+impl Iterator for IntoIter<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if scm_predicate(unsafe { scm_end_of_char_set_p(self.cursor.as_ptr()) }) {
+            None
+        } else {
+            let guile = unsafe { Guile::new_unchecked_ref() };
+            let ch = unsafe {
+                char::from_scm_unchecked(
+                    Scm::from_ptr_unchecked(scm_char_set_ref(
+                        self.char_set.0.as_ptr(),
+                        self.cursor.as_ptr(),
+                    )),
+                    guile,
+                )
+            };
+            unsafe {
+                scm_char_set_cursor_next(self.char_set.0.as_ptr(), self.cursor.as_ptr());
+            }
+
+            Some(ch)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, crate::with_guile, std::collections::HashSet};
@@ -187,4 +681,116 @@ mod tests {
         })
         .unwrap();
     }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_set_algebra() {
+        with_guile(|guile| {
+            let abc = || CharSet::from_chars(['a', 'b', 'c'], guile);
+            let bcd = || CharSet::from_chars(['b', 'c', 'd'], guile);
+
+            assert_eq!(
+                (abc() | bcd()).iter().collect::<HashSet<char>>(),
+                HashSet::from_iter(['a', 'b', 'c', 'd'])
+            );
+            assert_eq!(
+                (abc() & bcd()).iter().collect::<HashSet<char>>(),
+                HashSet::from_iter(['b', 'c'])
+            );
+            assert_eq!(
+                (abc() - bcd()).iter().collect::<HashSet<char>>(),
+                HashSet::from_iter(['a'])
+            );
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_set_complement() {
+        with_guile(|guile| {
+            let complement = !CharSet::from_chars(['a'], guile);
+            assert!(!complement.contains('a'));
+            assert!(complement.contains('b'));
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_set_adjoin_delete() {
+        with_guile(|guile| {
+            let set = CharSet::from_chars(['a', 'b'], guile);
+            assert!(set.adjoin('c').contains('c'));
+            assert!(!set.delete('a').contains('a'));
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_set_len_is_subset_eq() {
+        with_guile(|guile| {
+            let abc = CharSet::from_chars(['a', 'b', 'c'], guile);
+            assert_eq!(abc.len(), 3);
+            assert!(!abc.is_empty());
+            assert!(CharSet::from_chars(['a', 'b'], guile).is_subset(&abc));
+            assert!(!abc.is_subset(&CharSet::from_chars(['a', 'b'], guile)));
+            assert_eq!(abc, CharSet::from_chars(['c', 'b', 'a'], guile));
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_set_predefined() {
+        with_guile(|guile| {
+            assert!(CharSet::letter(guile).contains('a'));
+            assert!(!CharSet::letter(guile).contains('1'));
+            assert!(CharSet::digit(guile).contains('1'));
+            assert!(CharSet::whitespace(guile).contains(' '));
+            assert!(CharSet::letter_plus_digit(guile).contains('a'));
+            assert!(CharSet::letter_plus_digit(guile).contains('1'));
+            assert!(CharSet::hex_digit(guile).contains('f'));
+            assert!(!CharSet::hex_digit(guile).contains('g'));
+            assert!(CharSet::empty(guile).is_empty());
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_set_from_predicate() {
+        with_guile(|guile| {
+            let vowels = CharSet::from_predicate_over(
+                &CharSet::letter(guile),
+                |c| "aeiou".contains(c),
+                guile,
+            );
+            assert!(vowels.contains('a'));
+            assert!(!vowels.contains('b'));
+
+            let digits = CharSet::from_predicate(char::is_numeric, guile);
+            assert!(digits.contains('1'));
+            assert!(!digits.contains('a'));
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_set_fold_map() {
+        with_guile(|guile| {
+            assert_eq!(
+                CharSet::from_chars(['a', 'b', 'c'], guile).fold(0, |acc, _| acc + 1),
+                3
+            );
+
+            let upper =
+                CharSet::from_chars(['a', 'b', 'c'], guile).map(|ch| ch.to_ascii_uppercase());
+            assert!(upper.contains('A'));
+            assert!(!upper.contains('a'));
+        })
+        .unwrap();
+    }
 }
@@ -27,16 +27,13 @@ use {
         reference::{Ref, RefMut, ReprScm},
         scm::{Scm, ToScm, TryFromScm},
         sys::{
-            SCM, SCM_BOOL_F, SCM_UNDEFINED, scm_c_make_gsubr, scm_cdr, scm_hash_fold,
-            scm_hash_table_p, scm_make_hash_table,
+            SCM, SCM_BOOL_F, SCM_UNDEFINED, scm_c_make_gsubr, scm_cdr, scm_from_uintptr_t,
+            scm_hash_clear_x, scm_hash_fold, scm_hash_table_p, scm_make_hash_table, scm_set_cdr_x,
+            scm_to_uintptr_t,
         },
-        utils::CowCStrExt,
-    },
-    std::{
-        borrow::Cow,
-        ffi::{CStr, CString, c_void},
-        marker::PhantomData,
+        type_name::{TypeName, TypeNameBuilder},
     },
+    std::{ffi::c_void, marker::PhantomData},
 };
 
 trait ScmPartialEq {
@@ -46,8 +43,8 @@ trait ScmPartialEq {
     const REMOVE: unsafe extern "C" fn(_table: SCM, _key: SCM) -> SCM;
     /// Get a handle from `key` in `table` or `#f` if it doesn't exist.
     const GET_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM) -> SCM;
-    // /// Get the handle or insert it.
-    // const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM;
+    /// Get the handle or insert it, initialized to `init`.
+    const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM;
 }
 
 /// Hash map vtable that uses the `eq?` family.
@@ -59,8 +56,8 @@ impl ScmPartialEq for Eq {
         crate::sys::scm_hashq_remove_x;
     const GET_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM) -> SCM =
         crate::sys::scm_hashq_get_handle;
-    // const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM =
-    //     crate::sys::scm_hashq_create_handle_x;
+    const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM =
+        crate::sys::scm_hashq_create_handle_x;
 }
 
 /// Hash map vtable that uses the `eqv?` family.
@@ -72,8 +69,8 @@ impl ScmPartialEq for Eqv {
         crate::sys::scm_hashv_remove_x;
     const GET_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM) -> SCM =
         crate::sys::scm_hashv_get_handle;
-    // const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM =
-    //     crate::sys::scm_hashv_create_handle_x;
+    const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM =
+        crate::sys::scm_hashv_create_handle_x;
 }
 
 /// Hash map vtable that uses the `equal?` family.
@@ -85,8 +82,8 @@ impl ScmPartialEq for Equal {
         crate::sys::scm_hash_remove_x;
     const GET_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM) -> SCM =
         crate::sys::scm_hash_get_handle;
-    // const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM =
-    //     crate::sys::scm_hash_create_handle_x;
+    const CREATE_HANDLE: unsafe extern "C" fn(_table: SCM, _key: SCM, _init: SCM) -> SCM =
+        crate::sys::scm_hash_create_handle_x;
 }
 
 /// Hash map usable in scheme.
@@ -102,22 +99,41 @@ impl<'gm, K, V, E> HashMapInner<'gm, K, V, E>
 where
     E: ScmPartialEq,
 {
-    /// Create an empty hash map.
-    pub fn new(guile: &'gm Guile) -> Self {
-        Self {
+    /// Create an empty hash map, catching any exception (e.g. allocation failure)
+    /// `scm_make_hash_table` may throw instead of letting it `longjmp` out of Rust.
+    pub fn try_new(guile: &'gm Guile) -> Result<Self, Scm<'gm>> {
+        guile.catch_scm(|guile| Self {
             scm: Scm::from_ptr(unsafe { scm_make_hash_table(SCM_UNDEFINED) }, guile),
             _marker: PhantomData,
-        }
+        })
     }
-    /// Create a hash map with a specified capacity.
-    pub fn with_capacity(cap: usize, guile: &'gm Guile) -> Self {
-        Self {
+    /// Create an empty hash map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if Guile throws while constructing the table; see [Self::try_new].
+    pub fn new(guile: &'gm Guile) -> Self {
+        Self::try_new(guile).unwrap()
+    }
+
+    /// Create a hash map with a specified capacity, catching any exception
+    /// `scm_make_hash_table` may throw instead of letting it `longjmp` out of Rust.
+    pub fn try_with_capacity(cap: usize, guile: &'gm Guile) -> Result<Self, Scm<'gm>> {
+        guile.catch_scm(|guile| Self {
             scm: Scm::from_ptr(
                 unsafe { scm_make_hash_table(cap.to_scm(guile).as_ptr()) },
                 guile,
             ),
             _marker: PhantomData,
-        }
+        })
+    }
+    /// Create a hash map with a specified capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if Guile throws while constructing the table; see [Self::try_with_capacity].
+    pub fn with_capacity(cap: usize, guile: &'gm Guile) -> Self {
+        Self::try_with_capacity(cap, guile).unwrap()
     }
 
     /// Get the key from the hash table.
@@ -170,14 +186,43 @@ where
         let guile = unsafe { Guile::new_unchecked_ref() };
         let handle = unsafe { E::GET_HANDLE(self.scm.as_ptr(), key.to_scm(guile).as_ptr()) };
         if Pair::<K, V>::predicate(&Scm::from_ptr(handle, guile), guile) {
-            Some(unsafe { RefMut::new_unchecked(scm_cdr(handle)) })
+            Some(unsafe { RefMut::with_writer(scm_cdr(handle), write_cdr, handle, 0) })
         } else {
             None
         }
     }
 
+    /// Insert a key value pair into the hash map, catching any exception `E::SET` may throw
+    /// instead of letting it `longjmp` out of Rust.
+    ///
+    /// ```
+    /// # use garguile::{collections::hash_map::HashMap, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hm = HashMap::with_capacity(1, guile);
+    ///     assert!(hm.try_insert(0, true).is_ok());
+    ///     assert!(hm.get(0).is_some());
+    /// }).unwrap();
+    /// ```
+    pub fn try_insert(&mut self, key: K, val: V) -> Result<(), Scm<'gm>>
+    where
+        K: ToScm<'gm>,
+        V: ToScm<'gm>,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let scm = self.scm.as_ptr();
+        let key = key.to_scm(guile).as_ptr();
+        let val = val.to_scm(guile).as_ptr();
+        guile.catch_scm(|_| unsafe {
+            E::SET(scm, key, val);
+        })
+    }
     /// Insert a key value pair into the hash map.
     ///
+    /// # Panics
+    ///
+    /// Panics if Guile throws while inserting; see [Self::try_insert].
+    ///
     /// ```
     /// # use garguile::{collections::hash_map::HashMap, reference::Ref, with_guile};
     /// # #[cfg(not(miri))]
@@ -192,14 +237,7 @@ where
         K: ToScm<'gm>,
         V: ToScm<'gm>,
     {
-        let guile = unsafe { Guile::new_unchecked_ref() };
-        unsafe {
-            E::SET(
-                self.scm.as_ptr(),
-                key.to_scm(guile).as_ptr(),
-                val.to_scm(guile).as_ptr(),
-            );
-        }
+        self.try_insert(key, val).unwrap()
     }
     /// Remove a key value pair from the hash map.
     ///
@@ -230,6 +268,208 @@ where
         )
         .ok()
     }
+
+    /// Get the entry for `key` in the map.
+    ///
+    /// Unlike [Self::get_mut] followed by [Self::insert], this resolves to the occupied or
+    /// vacant state with a single hash lookup, since `E::CREATE_HANDLE` atomically returns the
+    /// existing `(key . val)` pair handle or inserts one initialized to a sentinel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::hash_map::HashMap, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hm = HashMap::<i32, bool>::with_capacity(1, guile);
+    ///     assert_eq!(hm.entry(0).or_insert(true).copied(), true);
+    ///     assert_eq!(hm.get(0).map(Ref::copied), Some(true));
+    /// }).unwrap();
+    /// ```
+    pub fn entry<'a>(&'a mut self, key: K) -> Entry<'a, 'gm, V>
+    where
+        K: ToScm<'gm>,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let key = key.to_scm(guile).as_ptr();
+        let handle = unsafe { E::CREATE_HANDLE(self.scm.as_ptr(), key, SCM_UNDEFINED) };
+        if unsafe { scm_cdr(handle) } == unsafe { SCM_UNDEFINED } {
+            Entry::Vacant(VacantEntry {
+                handle,
+                _marker: PhantomData,
+            })
+        } else {
+            Entry::Occupied(OccupiedEntry {
+                handle,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    /// Collect every `(key . val)` entry into a `Vec`, driving `scm_hash_fold` with a gsubr
+    /// callback that smuggles a pointer to the `Vec` through the accumulator via
+    /// [`scm_to_uintptr_t`]/[`scm_from_uintptr_t`], since guile hash tables expose no stable
+    /// cursor to iterate lazily.
+    fn collect_pairs(&self) -> Vec<(SCM, SCM)> {
+        let mut pairs: Vec<(SCM, SCM)> = Vec::new();
+        let callback = unsafe {
+            scm_c_make_gsubr(
+                c"hash-map-collect-callback".as_ptr(),
+                3,
+                0,
+                0,
+                hash_map_collect_callback as *mut c_void,
+            )
+        };
+        unsafe {
+            let accum = scm_from_uintptr_t((&raw mut pairs).addr());
+            scm_hash_fold(callback, accum, self.scm.as_ptr());
+        }
+        pairs
+    }
+
+    /// The number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::hash_map::HashMap, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hm = HashMap::with_capacity(1, guile);
+    ///     assert_eq!(hm.len(), 0);
+    ///     hm.insert(0, true);
+    ///     assert_eq!(hm.len(), 1);
+    /// }).unwrap();
+    /// ```
+    pub fn len(&self) -> usize {
+        self.collect_pairs().len()
+    }
+
+    /// Whether the map has no entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::hash_map::HashMap, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hm = HashMap::with_capacity(1, guile);
+    ///     assert!(hm.is_empty());
+    ///     hm.insert(0, true);
+    ///     assert!(!hm.is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every entry from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::hash_map::HashMap, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hm = HashMap::with_capacity(1, guile);
+    ///     hm.insert(0, true);
+    ///     hm.clear();
+    ///     assert!(hm.is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn clear(&mut self) {
+        unsafe {
+            scm_hash_clear_x(self.scm.as_ptr());
+        }
+    }
+
+    /// Iterate over every `(key, val)` entry in the map.
+    ///
+    /// The iteration order is unspecified, and the snapshot is taken up front; mutating the map
+    /// while iterating is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::hash_map::HashMap, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hm = HashMap::with_capacity(1, guile);
+    ///     hm.insert(0, true);
+    ///     assert_eq!(
+    ///         hm.iter().map(|(k, v)| (k.copied(), v.copied())).collect::<Vec<_>>(),
+    ///         vec![(0, true)],
+    ///     );
+    /// }).unwrap();
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Ref<'_, 'gm, K>, Ref<'_, 'gm, V>)>
+    where
+        K: TryFromScm<'gm>,
+        V: TryFromScm<'gm>,
+    {
+        self.collect_pairs()
+            .into_iter()
+            .map(|(key, val)| unsafe { (Ref::new_unchecked(key), Ref::new_unchecked(val)) })
+    }
+
+    /// Iterate over every key in the map. See [Self::iter].
+    pub fn keys(&self) -> impl Iterator<Item = Ref<'_, 'gm, K>>
+    where
+        K: TryFromScm<'gm>,
+        V: TryFromScm<'gm>,
+    {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Iterate over every value in the map. See [Self::iter].
+    pub fn values(&self) -> impl Iterator<Item = Ref<'_, 'gm, V>>
+    where
+        K: TryFromScm<'gm>,
+        V: TryFromScm<'gm>,
+    {
+        self.iter().map(|(_, val)| val)
+    }
+
+    /// Remove every entry for which `f` returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::hash_map::HashMap, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hm = HashMap::with_capacity(2, guile);
+    ///     hm.insert(0, true);
+    ///     hm.insert(1, false);
+    ///     hm.retain(|_, val| *val);
+    ///     assert_eq!(hm.len(), 1);
+    ///     assert!(hm.get(0).is_some());
+    ///     assert!(hm.get(1).is_none());
+    /// }).unwrap();
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        K: TryFromScm<'gm>,
+        V: TryFromScm<'gm>,
+        F: FnMut(&K, &V) -> bool,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let to_remove: Vec<SCM> = self
+            .collect_pairs()
+            .into_iter()
+            .filter_map(|(key, val)| {
+                let k = unsafe { K::from_scm_unchecked(Scm::from_ptr(key, guile), guile) };
+                let v = unsafe { V::from_scm_unchecked(Scm::from_ptr(val, guile), guile) };
+                if f(&k, &v) { None } else { Some(key) }
+            })
+            .collect();
+        for key in to_remove {
+            unsafe {
+                E::REMOVE(self.scm.as_ptr(), key);
+            }
+        }
+    }
 }
 unsafe impl<K, V, E> ReprScm for HashMapInner<'_, K, V, E> where E: ScmPartialEq {}
 impl<'gm, K, V, E> ToScm<'gm> for HashMapInner<'gm, K, V, E>
@@ -246,14 +486,15 @@ where
     V: TryFromScm<'gm>,
     E: ScmPartialEq,
 {
-    fn type_name() -> Cow<'static, CStr> {
-        CString::new(format!(
-            "(hash-map {} {})",
-            K::type_name().display(),
-            V::type_name().display()
-        ))
-        .map(Cow::Owned)
-        .unwrap_or(Cow::Borrowed(c"hash-map"))
+    fn type_name() -> TypeName {
+        let mut builder = TypeNameBuilder::new();
+        builder
+            .push(b"(hash-map ")
+            .push(K::type_name().to_bytes())
+            .push(b" ")
+            .push(V::type_name().to_bytes())
+            .push(b")");
+        builder.finish()
     }
 
     fn predicate(hm: &Scm<'gm>, guile: &'gm Guile) -> bool {
@@ -290,6 +531,88 @@ pub type HashMapQ<'gm, K, V> = HashMapInner<'gm, K, V, Eq>;
 /// Hash map that uses `eqv?` for comparison
 pub type HashMapV<'gm, K, V> = HashMapInner<'gm, K, V, Eqv>;
 
+/// A view into a single entry in a hash map, which may either be vacant or occupied.
+///
+/// See [HashMapInner::entry].
+pub enum Entry<'a, 'gm, V> {
+    /// An occupied entry, holding the `(key . val)` pair handle.
+    Occupied(OccupiedEntry<'a, 'gm, V>),
+    /// A vacant entry, holding a handle whose value has yet to be filled in.
+    Vacant(VacantEntry<'a, 'gm, V>),
+}
+impl<'a, 'gm, V> Entry<'a, 'gm, V>
+where
+    V: ToScm<'gm> + TryFromScm<'gm> + 'gm,
+{
+    /// Ensure a value is present by inserting `default` if the entry is vacant, then return a
+    /// mutable reference to the value.
+    pub fn or_insert(self, default: V) -> RefMut<'a, 'gm, V> {
+        self.or_insert_with(|| default)
+    }
+
+    /// Ensure a value is present by inserting the result of `default` if the entry is vacant,
+    /// then return a mutable reference to the value.
+    pub fn or_insert_with<F>(self, default: F) -> RefMut<'a, 'gm, V>
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Run `f` on a mutable reference to the value if the entry is occupied, then return the
+    /// (unchanged) entry.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(RefMut<'_, 'gm, V>),
+    {
+        if let Self::Occupied(entry) = &self {
+            f(unsafe { RefMut::with_writer(scm_cdr(entry.handle), write_cdr, entry.handle, 0) });
+        }
+        self
+    }
+}
+
+/// An occupied [Entry].
+pub struct OccupiedEntry<'a, 'gm, V> {
+    handle: SCM,
+    _marker: PhantomData<(&'a (), &'gm V)>,
+}
+impl<'a, 'gm, V> OccupiedEntry<'a, 'gm, V>
+where
+    V: TryFromScm<'gm>,
+{
+    /// Convert the entry into a mutable reference to its value, tied to the lifetime of the
+    /// borrow that produced this entry.
+    pub fn into_mut(self) -> RefMut<'a, 'gm, V> {
+        unsafe { RefMut::with_writer(scm_cdr(self.handle), write_cdr, self.handle, 0) }
+    }
+}
+
+/// A vacant [Entry].
+pub struct VacantEntry<'a, 'gm, V> {
+    handle: SCM,
+    _marker: PhantomData<(&'a (), &'gm V)>,
+}
+impl<'a, 'gm, V> VacantEntry<'a, 'gm, V>
+where
+    V: ToScm<'gm> + TryFromScm<'gm>,
+{
+    /// Write `val` into the handle's cdr and return a mutable reference to it.
+    pub fn insert(self, val: V) -> RefMut<'a, 'gm, V> {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        unsafe {
+            scm_set_cdr_x(self.handle, val.to_scm(guile).as_ptr());
+            RefMut::with_writer(scm_cdr(self.handle), write_cdr, self.handle, 0)
+        }
+    }
+}
+unsafe fn write_cdr(owner: SCM, _: usize, value: SCM) {
+    unsafe { scm_set_cdr_x(owner, value) }
+}
+
 extern "C" fn hash_map_fold_callback<'gm, K, V>(key: SCM, val: SCM, accum: SCM) -> SCM
 where
     K: TryFromScm<'gm>,
@@ -305,3 +628,11 @@ where
     .to_scm(guile)
     .as_ptr()
 }
+
+/// `accum` must be a pointer (smuggled through [`scm_from_uintptr_t`]) to a live
+/// `Vec<(SCM, SCM)>` that outlives the fold; see [`HashMapInner::collect_pairs`].
+extern "C" fn hash_map_collect_callback(key: SCM, val: SCM, accum: SCM) -> SCM {
+    let pairs = unsafe { &mut *(scm_to_uintptr_t(accum) as *mut Vec<(SCM, SCM)>) };
+    pairs.push((key, val));
+    accum
+}
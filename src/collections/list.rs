@@ -32,13 +32,13 @@ use {
         subr::Proc,
         sys::{
             SCM, SCM_EOL, scm_car, scm_cdr, scm_char_set_to_list, scm_cons, scm_hook_to_list,
-            scm_list_p, scm_vector_to_list,
+            scm_length, scm_list_p, scm_reverse, scm_set_car_x, scm_to_uintptr_t,
+            scm_vector_to_list,
         },
-        utils::{CowCStrExt, scm_predicate},
+        type_name::{TypeName, TypeNameBuilder},
+        utils::scm_predicate,
     },
     std::{
-        borrow::Cow,
-        ffi::{CStr, CString},
         iter::{self, FusedIterator},
         marker::PhantomData,
     },
@@ -90,6 +90,11 @@ impl<'gm, T> List<'gm, T> {
     }
 
     /// Create a list in reverse order of the iterator.
+    ///
+    /// For example, `List::from_iter(['a', 'b', 'c'], guile)` produces the list `(c b a)`, since
+    /// each item is consed onto the front of the ones seen before it. Use
+    /// [Self::from_iter_ordered], or collect into a `List` via the real [FromIterator] impl, if
+    /// you want the iteration order preserved instead.
     pub fn from_iter<I>(iter: I, guile: &'gm Guile) -> Self
     where
         I: IntoIterator<Item = T>,
@@ -99,6 +104,34 @@ impl<'gm, T> List<'gm, T> {
         list.extend(iter);
         list
     }
+
+    /// Create a list preserving the order of the iterator.
+    ///
+    /// This builds the list in reverse like [Self::from_iter], then reverses the result once, so
+    /// it's still O(n) rather than the O(n^2) a `push_back` loop would cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{collections::list::List, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let list = List::from_iter_ordered([1, 2, 3], guile);
+    ///     assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// }).unwrap();
+    /// ```
+    pub fn from_iter_ordered<I>(iter: I, guile: &'gm Guile) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: ToScm<'gm>,
+    {
+        let reversed = Self::from_iter(iter, guile);
+        Self {
+            scm: Scm::from_ptr(unsafe { scm_reverse(reversed.scm.as_ptr()) }, guile),
+            _marker: PhantomData,
+        }
+    }
+
     pub fn push_front(&mut self, item: T)
     where
         T: ToScm<'gm>,
@@ -106,19 +139,64 @@ impl<'gm, T> List<'gm, T> {
         self.extend(iter::once(item));
     }
 
+    /// Append `item` to the end of the list, keeping every existing element in place.
+    pub fn push_back(&mut self, item: T)
+    where
+        T: ToScm<'gm>,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let reversed = unsafe { scm_reverse(self.scm.as_ptr()) };
+        let consed = unsafe { scm_cons(item.to_scm(guile).as_ptr(), reversed) };
+        self.scm = Scm::from_ptr(unsafe { scm_reverse(consed) }, guile);
+    }
+
     pub fn is_empty(&self) -> bool {
         self.scm.is_eol()
     }
 
+    /// Returns the length of the list, or `None` if it isn't a proper list (e.g. it's improper or
+    /// circular), as determined by [scm_list_p].
+    pub fn try_len(&self) -> Option<usize> {
+        scm_predicate(unsafe { scm_list_p(self.scm.as_ptr()) })
+            .then(|| unsafe { scm_to_uintptr_t(scm_length(self.scm.as_ptr())) })
+    }
+
+    /// Panics if the list is improper or circular; see [Self::try_len].
+    pub fn len(&self) -> usize {
+        self.try_len().expect("list should be a proper list")
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the list is improper or circular; see [Self::try_len].
     pub fn iter<'a>(&'a self) -> Iter<'a, 'gm, T> {
         Iter {
-            car: self.scm.as_ptr(),
+            cursor: Cursor::Walking(self.scm.as_ptr()),
+            len: self.len(),
             _marker: PhantomData,
         }
     }
+    /// # Panics
+    ///
+    /// Panics if the list is improper or circular; see [Self::try_len].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{collections::list::List, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut list = List::from_iter_ordered([1, 2, 3], guile);
+    ///     list.iter_mut()
+    ///         .enumerate()
+    ///         .for_each(|(i, mut r)| r.set((i as i32 + 1) * 10));
+    ///     assert_eq!(list.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+    /// }).unwrap();
+    /// ```
     pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, 'gm, T> {
         IterMut {
-            car: self.scm.as_ptr(),
+            cursor: Cursor::Walking(self.scm.as_ptr()),
+            len: self.len(),
             _marker: PhantomData,
         }
     }
@@ -138,6 +216,19 @@ where
         self.scm = unsafe { Scm::from_ptr_unchecked(pair) };
     }
 }
+impl<'gm, T> FromIterator<T> for List<'gm, T>
+where
+    T: ToScm<'gm>,
+{
+    /// Collect into a list, preserving the order of the iterator; see [Self::from_iter_ordered].
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self::from_iter_ordered(iter, guile)
+    }
+}
 impl<'gm, T> From<ByteVector<'gm, T>> for List<'gm, T>
 where
     T: ByteVectorType,
@@ -181,8 +272,16 @@ where
     type Item = T;
     type IntoIter = IntoIter<'gm, T>;
 
+    /// # Panics
+    ///
+    /// Panics if the list is improper or circular; see [Self::try_len].
     fn into_iter(self) -> IntoIter<'gm, T> {
-        IntoIter(self)
+        let len = self.len();
+        IntoIter {
+            cursor: Cursor::Walking(self.scm.as_ptr()),
+            len,
+            _marker: PhantomData,
+        }
     }
 }
 impl<'a, 'gm, T> IntoIterator for &'a List<'gm, T>
@@ -221,17 +320,21 @@ impl<'gm, T> TryFromScm<'gm> for List<'gm, T>
 where
     T: TryFromScm<'gm>,
 {
-    fn type_name() -> Cow<'static, CStr> {
-        CString::new(format!("(list {})", T::type_name().display()))
-            .map(Cow::Owned)
-            .unwrap_or(Cow::Borrowed(c"list"))
+    fn type_name() -> TypeName {
+        let mut builder = TypeNameBuilder::new();
+        builder
+            .push(b"(list ")
+            .push(T::type_name().to_bytes())
+            .push(b")");
+        builder.finish()
     }
     fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
         scm_predicate(unsafe { scm_list_p(scm.as_ptr()) }) && {
-            IntoIter(List {
-                scm: unsafe { scm.copy_unchecked() },
-                _marker: PhantomData::<Scm>,
-            })
+            IntoIter::<Scm> {
+                cursor: Cursor::Walking(unsafe { scm.copy_unchecked() }.as_ptr()),
+                len: unsafe { scm_to_uintptr_t(scm_length(scm.as_ptr())) },
+                _marker: PhantomData,
+            }
             .all(|i| T::predicate(&i, guile))
         }
     }
@@ -243,13 +346,98 @@ where
     }
 }
 
-pub struct IntoIter<'gm, T>(List<'gm, T>);
+/// Shared walk state for [IntoIter], [Iter], and [IterMut].
+///
+/// Starts out as a plain pointer walk down the cons cells, which is all forward iteration needs.
+/// The first call to `next_back` materializes the remaining cells into a `Vec`, so
+/// `rev()`/`last()`/`nth_back()` don't each re-walk the list from the front.
+///
+/// Each item yielded is `(cell, car)`: the owning cons cell alongside its car, so [IterMut] can
+/// hand out a [RefMut] that writes back through [scm_set_car_x] on that cell.
+#[derive(Clone)]
+enum Cursor {
+    Walking(SCM),
+    Buffered {
+        items: Vec<(SCM, SCM)>,
+        front: usize,
+    },
+}
+impl Cursor {
+    fn next(&mut self) -> Option<(SCM, SCM)> {
+        match self {
+            Self::Walking(cell) => {
+                (!unsafe { Scm::from_ptr_unchecked(*cell) }.is_eol()).then(|| {
+                    let this = *cell;
+                    let item = unsafe { scm_car(this) };
+                    *cell = unsafe { scm_cdr(this) };
+                    (this, item)
+                })
+            }
+            Self::Buffered { items, front } => (*front < items.len()).then(|| {
+                let item = items[*front];
+                *front += 1;
+                item
+            }),
+        }
+    }
+
+    fn next_back(&mut self) -> Option<(SCM, SCM)> {
+        if let Self::Walking(cell) = *self {
+            let mut items = Vec::new();
+            let mut cursor = cell;
+            while !unsafe { Scm::from_ptr_unchecked(cursor) }.is_eol() {
+                items.push((cursor, unsafe { scm_car(cursor) }));
+                cursor = unsafe { scm_cdr(cursor) };
+            }
+            *self = Self::Buffered { items, front: 0 };
+        }
+        match self {
+            Self::Buffered { items, front } => (*front < items.len()).then(|| items.pop().unwrap()),
+            Self::Walking(_) => unreachable!(),
+        }
+    }
+}
+unsafe fn write_car(owner: SCM, _: usize, value: SCM) {
+    unsafe { scm_set_car_x(owner, value) }
+}
+
+pub struct IntoIter<'gm, T> {
+    cursor: Cursor,
+    len: usize,
+    _marker: PhantomData<&'gm T>,
+}
 impl<'gm, T> From<IntoIter<'gm, T>> for List<'gm, T> {
-    fn from(IntoIter(lst): IntoIter<'gm, T>) -> List<'gm, T> {
-        lst
+    fn from(iter: IntoIter<'gm, T>) -> List<'gm, T> {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let scm = match iter.cursor {
+            Cursor::Walking(car) => car,
+            Cursor::Buffered { items, front } => items[front..]
+                .iter()
+                .rev()
+                .fold(unsafe { SCM_EOL }, |cdr, &(_, car)| unsafe {
+                    scm_cons(car, cdr)
+                }),
+        };
+        List {
+            scm: Scm::from_ptr(scm, guile),
+            _marker: PhantomData,
+        }
     }
 }
+impl<'gm, T> ExactSizeIterator for IntoIter<'gm, T> where T: TryFromScm<'gm> {}
 impl<'gm, T> FusedIterator for IntoIter<'gm, T> where T: TryFromScm<'gm> {}
+impl<'gm, T> DoubleEndedIterator for IntoIter<'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    fn next_back(&mut self) -> Option<T> {
+        let (_, car) = self.cursor.next_back()?;
+        self.len -= 1;
+
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Some(unsafe { T::from_scm_unchecked(Scm::from_ptr(car, guile), guile) })
+    }
+}
 impl<'gm, T> Iterator for IntoIter<'gm, T>
 where
     T: TryFromScm<'gm>,
@@ -257,59 +445,154 @@ where
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
-        if self.0.scm.is_eol() {
-            None
-        } else {
-            let [car, cdr] = [scm_car, scm_cdr]
-                .map(|morphism| unsafe { morphism(self.0.scm.as_ptr()) })
-                .map(|ptr| unsafe { Scm::from_ptr_unchecked(ptr) });
-            self.0.scm = cdr;
-
-            let guile = unsafe { Guile::new_unchecked_ref() };
-            Some(unsafe { T::from_scm_unchecked(car, guile) })
-        }
+        let (_, car) = self.cursor.next()?;
+        self.len -= 1;
+
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Some(unsafe { T::from_scm_unchecked(Scm::from_ptr(car, guile), guile) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+/// A list that has only been checked to be a proper Guile list.
+///
+/// [List]'s [TryFromScm] impl eagerly walks the whole pair chain checking every element against
+/// `T`, paying an `O(n)` cost on top of the traversal the caller is about to do anyway.
+/// [Self::get_unchecked_shallow] checks nothing, and turning the result into an iterator only
+/// calls [scm_list_p] (panicking on an improper list), deferring the per-element
+/// [TryFromScm::predicate] check to [ShallowIntoIter::next], where a mismatch panics instead of
+/// being rejected up front. Prefer [List] when you want strict, early validation; prefer this when
+/// you intend to iterate a large list once.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct RawList<'gm, T>(List<'gm, T>)
+where
+    T: TryFromScm<'gm>;
+impl<'gm, T> RawList<'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    /// Wrap `scm` as a list without checking it or any element's type.
+    ///
+    /// # Safety
+    ///
+    /// `scm` must be a proper list (see [scm_list_p]). No checking of the type of the elements
+    /// is performed here; it happens lazily in [ShallowIntoIter::next].
+    pub unsafe fn get_unchecked_shallow(scm: Scm<'gm>) -> Self {
+        Self(List {
+            scm,
+            _marker: PhantomData,
+        })
+    }
+}
+impl<'gm, T> IntoIterator for RawList<'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    type Item = T;
+    type IntoIter = ShallowIntoIter<'gm, T>;
+
+    /// # Panics
+    ///
+    /// Panics if the list is improper or circular; see [List::try_len].
+    fn into_iter(self) -> Self::IntoIter {
+        ShallowIntoIter(self.0.into_iter())
     }
 }
 
-#[derive(Clone, Copy)]
+/// An owning iterator over a [RawList] that type-checks each element lazily.
+///
+/// # Panics
+///
+/// [Iterator::next] panics if the next element does not satisfy [TryFromScm::predicate].
+pub struct ShallowIntoIter<'gm, T>(IntoIter<'gm, T>)
+where
+    T: TryFromScm<'gm>;
+impl<'gm, T> ExactSizeIterator for ShallowIntoIter<'gm, T> where T: TryFromScm<'gm> {}
+impl<'gm, T> FusedIterator for ShallowIntoIter<'gm, T> where T: TryFromScm<'gm> {}
+impl<'gm, T> Iterator for ShallowIntoIter<'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (_, car) = self.0.cursor.next()?;
+        self.0.len -= 1;
+
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let scm = Scm::from_ptr(car, guile);
+        assert!(
+            T::predicate(&scm, guile),
+            "list element did not match expected type `{}`",
+            T::type_name().as_ref().to_string_lossy()
+        );
+        Some(unsafe { T::from_scm_unchecked(scm, guile) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+#[derive(Clone)]
 pub struct Iter<'a, 'gm, T> {
-    car: SCM,
+    cursor: Cursor,
+    len: usize,
     _marker: PhantomData<&'a &'gm T>,
 }
+impl<T> ExactSizeIterator for Iter<'_, '_, T> {}
 impl<T> FusedIterator for Iter<'_, '_, T> {}
+impl<T> DoubleEndedIterator for Iter<'_, '_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (_, car) = self.cursor.next_back()?;
+        self.len -= 1;
+        Some(unsafe { Ref::new_unchecked(car) })
+    }
+}
 impl<'a, 'gm, T> Iterator for Iter<'a, 'gm, T> {
     type Item = Ref<'a, 'gm, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if unsafe { Scm::from_ptr_unchecked(self.car) }.is_eol() {
-            None
-        } else {
-            let [car, cdr] = [scm_car, scm_cdr].map(|morphism| unsafe { morphism(self.car) });
-            self.car = cdr;
+        let (_, car) = self.cursor.next()?;
+        self.len -= 1;
+        Some(unsafe { Ref::new_unchecked(car) })
+    }
 
-            Some(unsafe { Ref::new_unchecked(car) })
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct IterMut<'a, 'gm, T> {
-    car: SCM,
+    cursor: Cursor,
+    len: usize,
     _marker: PhantomData<&'a &'gm T>,
 }
+impl<T> ExactSizeIterator for IterMut<'_, '_, T> {}
 impl<T> FusedIterator for IterMut<'_, '_, T> {}
+impl<T> DoubleEndedIterator for IterMut<'_, '_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (cell, car) = self.cursor.next_back()?;
+        self.len -= 1;
+        Some(unsafe { RefMut::with_writer(car, write_car, cell, 0) })
+    }
+}
 impl<'a, 'gm, T> Iterator for IterMut<'a, 'gm, T> {
     type Item = RefMut<'a, 'gm, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if unsafe { Scm::from_ptr_unchecked(self.car) }.is_eol() {
-            None
-        } else {
-            let [car, cdr] = [scm_car, scm_cdr].map(|morphism| unsafe { morphism(self.car) });
-            self.car = cdr;
+        let (cell, car) = self.cursor.next()?;
+        self.len -= 1;
+        Some(unsafe { RefMut::with_writer(car, write_car, cell, 0) })
+    }
 
-            Some(unsafe { RefMut::new_unchecked(car) })
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 
@@ -322,8 +605,8 @@ impl<'gm> Null<'gm> {
 }
 unsafe impl ReprScm for Null<'_> {}
 impl<'gm> TryFromScm<'gm> for Null<'gm> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"null")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"null")
     }
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
         scm.is_eol()
@@ -406,4 +689,95 @@ mod tests {
         })
         .unwrap();
     }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn list_from_iter_ordered() {
+        with_guile(|guile| {
+            assert_eq!(
+                List::from_iter_ordered('a'..='c', guile)
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                ['a', 'b', 'c'],
+            );
+            assert_eq!(
+                ('a'..='c')
+                    .collect::<List<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                ['a', 'b', 'c'],
+            );
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn list_push_back() {
+        with_guile(|guile| {
+            let mut list = List::from_iter_ordered([1, 2], guile);
+            list.push_back(3);
+            assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn list_len() {
+        with_guile(|guile| {
+            assert_eq!(List::<i32>::new(guile).len(), 0);
+            assert_eq!(List::from_iter_ordered([1, 2, 3], guile).len(), 3);
+            assert_eq!(List::from_iter_ordered([1, 2, 3], guile).iter().len(), 3);
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn raw_list_shallow_iter() {
+        with_guile(|guile| {
+            let scm = List::from_iter_ordered([1, 2, 3], guile).to_scm(guile);
+            let raw = unsafe { RawList::<i32>::get_unchecked_shallow(scm) };
+            assert_eq!(raw.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    #[should_panic(expected = "list element did not match expected type")]
+    fn raw_list_shallow_iter_panics_on_mismatch() {
+        with_guile(|guile| {
+            let scm = List::from_iter_ordered([1, 2, 3], guile).to_scm(guile);
+            let raw = unsafe { RawList::<String>::get_unchecked_shallow(scm) };
+            raw.into_iter().for_each(|_| {});
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn list_double_ended() {
+        with_guile(|guile| {
+            let list = List::from_iter_ordered([1, 2, 3, 4], guile);
+            assert_eq!(
+                list.iter().map(Ref::into_inner).rev().collect::<Vec<_>>(),
+                [4, 3, 2, 1],
+            );
+            assert_eq!(
+                List::from_iter_ordered([1, 2, 3], guile).into_iter().last(),
+                Some(3),
+            );
+
+            let mut iter = List::from_iter_ordered([1, 2, 3, 4], guile).into_iter();
+            assert_eq!(iter.next(), Some(1));
+            assert_eq!(iter.next_back(), Some(4));
+            assert_eq!(iter.next_back(), Some(3));
+            assert_eq!(iter.next(), Some(2));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        })
+        .unwrap();
+    }
 }
@@ -20,14 +20,11 @@ use {
         Guile,
         reference::{Ref, RefMut, ReprScm},
         scm::{Scm, ToScm, TryFromScm},
-        sys::{scm_car, scm_cdr, scm_cons, scm_is_pair, scm_set_car_x, scm_set_cdr_x},
-        utils::{CowCStrExt, c_predicate},
-    },
-    std::{
-        borrow::Cow,
-        ffi::{CStr, CString},
-        marker::PhantomData,
+        sys::{SCM, scm_car, scm_cdr, scm_cons, scm_is_pair, scm_set_car_x, scm_set_cdr_x},
+        type_name::{TypeName, TypeNameBuilder},
+        utils::c_predicate,
     },
+    std::marker::PhantomData,
 };
 
 /// Tuples with 2 elements.
@@ -108,7 +105,7 @@ impl<'gm, L, R> Pair<'gm, L, R> {
     /// }).unwrap();
     /// ```
     pub fn as_mut_car<'a>(&'a mut self) -> RefMut<'a, 'gm, L> {
-        unsafe { RefMut::new_unchecked(scm_car(self.scm.as_ptr())) }
+        unsafe { RefMut::with_writer(scm_car(self.scm.as_ptr()), write_car, self.scm.as_ptr(), 0) }
     }
     /// Get a mutable reference to the right side of the pair.
     ///
@@ -125,9 +122,15 @@ impl<'gm, L, R> Pair<'gm, L, R> {
     /// }).unwrap();
     /// ```
     pub fn as_mut_cdr<'a>(&'a mut self) -> RefMut<'a, 'gm, R> {
-        unsafe { RefMut::new_unchecked(scm_cdr(self.scm.as_ptr())) }
+        unsafe { RefMut::with_writer(scm_cdr(self.scm.as_ptr()), write_cdr, self.scm.as_ptr(), 0) }
     }
 }
+unsafe fn write_car(owner: SCM, _: usize, value: SCM) {
+    unsafe { scm_set_car_x(owner, value) }
+}
+unsafe fn write_cdr(owner: SCM, _: usize, value: SCM) {
+    unsafe { scm_set_cdr_x(owner, value) }
+}
 impl<'gm, L, R> Pair<'gm, L, R>
 where
     L: ToScm<'gm>,
@@ -208,14 +211,15 @@ where
     L: TryFromScm<'gm>,
     R: TryFromScm<'gm>,
 {
-    fn type_name() -> Cow<'static, CStr> {
-        CString::new(format!(
-            "({} . {})",
-            L::type_name().display(),
-            R::type_name().display()
-        ))
-        .map(Cow::Owned)
-        .unwrap_or_else(|_| Cow::Borrowed(c"pair"))
+    fn type_name() -> TypeName {
+        let mut builder = TypeNameBuilder::new();
+        builder
+            .push(b"(")
+            .push(L::type_name().to_bytes())
+            .push(b" . ")
+            .push(R::type_name().to_bytes())
+            .push(b")");
+        builder.finish()
     }
     fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
         let pair = scm.as_ptr();
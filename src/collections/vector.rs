@@ -27,20 +27,26 @@ use {
         reference::{Ref, RefMut, ReprScm},
         scm::{Scm, ToScm, TryFromScm},
         sys::{
-            SCM, scm_array_handle_release, scm_c_make_vector, scm_t_array_handle, scm_vector,
-            scm_vector_elements, scm_vector_p, scm_vector_writable_elements,
+            SCM, SCM_BOOL_F, scm_array_handle_release, scm_c_make_vector, scm_c_vector_set_x,
+            scm_t_array_handle, scm_vector, scm_vector_elements, scm_vector_p,
+            scm_vector_writable_elements,
         },
-        utils::{CowCStrExt, scm_predicate},
+        type_name::{TypeName, TypeNameBuilder},
+        utils::scm_predicate,
     },
     std::{
-        borrow::Cow,
-        ffi::{CStr, CString},
         iter::FusedIterator,
         marker::PhantomData,
         num::NonZeroUsize,
+        ops::{Deref, DerefMut, Index},
+        slice,
     },
 };
 
+unsafe fn write_elem(owner: SCM, index: usize, value: SCM) {
+    unsafe { scm_c_vector_set_x(owner, index, value) }
+}
+
 /// Vector backed by a contiguous block of memory.
 #[repr(transparent)]
 pub struct Vector<'gm, T> {
@@ -56,6 +62,24 @@ impl<'gm, T> From<List<'gm, T>> for Vector<'gm, T> {
         }
     }
 }
+impl<'gm, T> From<&[T]> for Vector<'gm, T>
+where
+    T: Copy + ToScm<'gm>,
+{
+    fn from(slice: &[T]) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self::from_iter(slice.iter().copied(), guile)
+    }
+}
+impl<'gm, T> From<std::vec::Vec<T>> for Vector<'gm, T>
+where
+    T: ToScm<'gm>,
+{
+    fn from(vec: std::vec::Vec<T>) -> Self {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        Self::from_iter(vec, guile)
+    }
+}
 impl<'gm, T> Vector<'gm, T> {
     /// Create a vector of copied items.
     ///
@@ -83,6 +107,39 @@ impl<'gm, T> Vector<'gm, T> {
         }
     }
 
+    /// Build a vector directly from an [ExactSizeIterator], without materializing an
+    /// intermediate [List] first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::vector::Vector, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vec = Vector::from_iter([1, 2, 3], guile);
+    ///     assert_eq!(vec.iter().map(Ref::copied).collect::<Vec<_>>(), [1, 2, 3]);
+    /// }).unwrap();
+    /// ```
+    pub fn from_iter<I>(iter: I, guile: &'gm Guile) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+        T: ToScm<'gm>,
+    {
+        let iter = iter.into_iter();
+        let ptr = unsafe { scm_c_make_vector(iter.len(), SCM_BOOL_F) };
+        for (i, item) in iter.enumerate() {
+            unsafe {
+                scm_c_vector_set_x(ptr, i, item.to_scm(guile).as_ptr());
+            }
+        }
+
+        Self {
+            scm: Scm::from_ptr(ptr, guile),
+            _marker: PhantomData,
+        }
+    }
+
     /// Get an immutable iterator.
     ///
     /// # Examples
@@ -157,12 +214,269 @@ impl<'gm, T> Vector<'gm, T> {
 
         IterMut {
             handle,
+            vector: self.scm.as_ptr(),
             ptr,
             len: NonZeroUsize::new(len),
             step,
+            front: 0,
             _marker: PhantomData,
         }
     }
+
+    /// The number of elements in the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::vector::Vector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(Vector::new(true, 10, guile).len(), 10);
+    /// }).unwrap();
+    /// ```
+    pub fn len(&self) -> usize {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        unsafe {
+            scm_vector_elements(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            );
+            scm_array_handle_release(&raw mut handle);
+        }
+        len
+    }
+
+    /// Whether the vector has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::vector::Vector, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert!(Vector::new(true, 0, guile).is_empty());
+    ///     assert!(!Vector::new(true, 1, guile).is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the element at `i`, opening an array handle to read it and releasing it before
+    /// returning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::vector::Vector, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vec = Vector::new(true, 1, guile);
+    ///     assert_eq!(vec.get(0).map(Ref::copied), Some(true));
+    ///     assert!(vec.get(1).is_none());
+    /// }).unwrap();
+    /// ```
+    pub fn get<'a>(&'a self, i: usize) -> Option<Ref<'a, 'gm, T>>
+    where
+        T: TryFromScm<'gm>,
+    {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            scm_vector_elements(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        let elem = (i < len).then(|| unsafe {
+            Ref::new_unchecked(ptr.offset(isize::try_from(i).unwrap() * step).read())
+        });
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+        elem
+    }
+
+    /// Get a mutable reference to the element at `i`, opening an array handle to read it and
+    /// releasing it before returning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::{list::List, pair::Pair, vector::Vector}, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut vec = Vector::from(List::from_iter([Pair::new(false, (), guile)], guile));
+    ///     vec.get_mut(0).unwrap().set_car(true);
+    ///     assert!(vec.get(0).unwrap().as_car().copied());
+    ///     assert!(vec.get_mut(1).is_none());
+    ///
+    ///     let mut vec = Vector::new(0, 2, guile);
+    ///     vec.get_mut(1).unwrap().set(10);
+    ///     assert_eq!(vec.into_iter().collect::<Vec<_>>(), [0, 10]);
+    /// }).unwrap();
+    /// ```
+    pub fn get_mut<'a>(&'a mut self, i: usize) -> Option<RefMut<'a, 'gm, T>>
+    where
+        T: TryFromScm<'gm>,
+    {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            scm_vector_writable_elements(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        let elem = (i < len).then(|| unsafe {
+            RefMut::with_writer(
+                ptr.offset(isize::try_from(i).unwrap() * step).read(),
+                write_elem,
+                self.scm.as_ptr(),
+                i,
+            )
+        });
+        unsafe {
+            scm_array_handle_release(&raw mut handle);
+        }
+        elem
+    }
+
+    /// Set the element at `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::vector::Vector, reference::Ref, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut vec = Vector::new(false, 1, guile);
+    ///     vec.set(0, true);
+    ///     assert_eq!(vec.get(0).map(Ref::copied), Some(true));
+    /// }).unwrap();
+    /// ```
+    pub fn set(&mut self, i: usize, value: T)
+    where
+        T: ToScm<'gm> + TryFromScm<'gm>,
+    {
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let value = value.to_scm(guile).as_ptr();
+
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            scm_vector_writable_elements(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+        assert!(
+            i < len,
+            "index out of bounds: the len is {len} but the index is {i}"
+        );
+        unsafe {
+            ptr.offset(isize::try_from(i).unwrap() * step).write(value);
+            scm_array_handle_release(&raw mut handle);
+        }
+    }
+
+    /// Borrow the elements as a contiguous slice, or `None` if the underlying array handle
+    /// reports a step other than `1` (e.g. a shared, strided sub-vector).
+    ///
+    /// The array handle is kept open for as long as the returned [Slice] is alive, and released
+    /// when it's dropped; see [Slice].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::vector::Vector, scm::{Scm, ToScm}, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let vector: Vector<Scm> = Vector::from_iter([1, 2, 3].map(|i: i32| i.to_scm(guile)), guile);
+    ///     assert_eq!(vector.as_slice().unwrap().len(), 3);
+    /// }).unwrap();
+    /// ```
+    pub fn as_slice(&self) -> Option<Slice<'_, 'gm, T>>
+    where
+        T: ReprScm,
+    {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            scm_vector_elements(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        if step == 1 {
+            Some(Slice {
+                handle,
+                ptr: ptr.cast(),
+                len,
+                _marker: PhantomData,
+            })
+        } else {
+            unsafe {
+                scm_array_handle_release(&raw mut handle);
+            }
+            None
+        }
+    }
+
+    /// See [Self::as_slice].
+    pub fn as_mut_slice(&mut self) -> Option<SliceMut<'_, 'gm, T>>
+    where
+        T: ReprScm,
+    {
+        let mut handle = Default::default();
+        let mut len = 0;
+        let mut step = 0;
+        let ptr = unsafe {
+            scm_vector_writable_elements(
+                self.scm.as_ptr(),
+                &raw mut handle,
+                &raw mut len,
+                &raw mut step,
+            )
+        };
+
+        if step == 1 {
+            Some(SliceMut {
+                handle,
+                ptr: ptr.cast(),
+                len,
+                _marker: PhantomData,
+            })
+        } else {
+            unsafe {
+                scm_array_handle_release(&raw mut handle);
+            }
+            None
+        }
+    }
 }
 impl<'gm, T> IntoIterator for Vector<'gm, T>
 where
@@ -215,6 +529,26 @@ where
         self.iter_mut()
     }
 }
+impl<'a, 'gm, T> Index<usize> for &'a Vector<'gm, T>
+where
+    T: TryFromScm<'gm>,
+{
+    type Output = Ref<'a, 'gm, T>;
+
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    ///
+    /// Unlike [Vector::get], `index` must return a borrow, but each [Ref] is materialized fresh
+    /// from the array handle on every lookup rather than living inside `self`; since [Ref] is
+    /// just a bag of bits around the element's `SCM`, this leaks one small, fixed-size
+    /// allocation per call to give it somewhere to live. Prefer [Vector::get] in hot paths.
+    fn index(&self, i: usize) -> &Self::Output {
+        Box::leak(Box::new(self.get(i).unwrap_or_else(|| {
+            panic!("index out of bounds: the index is {i}")
+        })))
+    }
+}
 unsafe impl<'gm, T> ReprScm for Vector<'gm, T> {}
 impl<'gm, T> ToScm<'gm> for Vector<'gm, T> {
     fn to_scm(self, _: &'gm Guile) -> Scm<'gm> {
@@ -225,14 +559,44 @@ impl<'gm, T> TryFromScm<'gm> for Vector<'gm, T>
 where
     T: TryFromScm<'gm>,
 {
-    fn type_name() -> Cow<'static, CStr> {
-        CString::new(format!("(vector {})", T::type_name().display()))
-            .map(Cow::Owned)
-            .unwrap_or(Cow::Borrowed(c"vector"))
+    fn type_name() -> TypeName {
+        let mut builder = TypeNameBuilder::new();
+        builder
+            .push(b"(vector ")
+            .push(T::type_name().to_bytes())
+            .push(b")");
+        builder.finish()
     }
 
-    fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
-        scm_predicate(unsafe { scm_vector_p(scm.as_ptr()) }) && { todo!("type check all values") }
+    fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
+        scm_predicate(unsafe { scm_vector_p(scm.as_ptr()) }) && {
+            let mut handle = Default::default();
+            let mut len = 0;
+            let mut step = 0;
+            let ptr = unsafe {
+                scm_vector_elements(scm.as_ptr(), &raw mut handle, &raw mut len, &raw mut step)
+            };
+
+            // Ensures `handle` is released on every exit path, including the early `false`
+            // returned by `Iterator::all`.
+            struct Guard(scm_t_array_handle);
+            impl Drop for Guard {
+                fn drop(&mut self) {
+                    unsafe {
+                        scm_array_handle_release(&raw mut self.0);
+                    }
+                }
+            }
+            let _guard = Guard(handle);
+
+            (0..len).all(|i| {
+                let elem = Scm::from_ptr(
+                    unsafe { ptr.offset(isize::try_from(i).unwrap() * step).read() },
+                    guile,
+                );
+                T::predicate(&elem, guile)
+            })
+        }
     }
 
     unsafe fn from_scm_unchecked(scm: Scm<'gm>, _: &'gm Guile) -> Self {
@@ -243,6 +607,58 @@ where
     }
 }
 
+/// A borrowed, contiguous view of a [Vector]'s elements; see [Vector::as_slice].
+///
+/// Owns the array handle backing the slice and releases it (via [scm_array_handle_release]) on
+/// [Drop], so the handle stays open for exactly as long as the borrow is alive.
+pub struct Slice<'a, 'gm, T> {
+    handle: scm_t_array_handle,
+    ptr: *const T,
+    len: usize,
+    _marker: PhantomData<&'a &'gm [T]>,
+}
+impl<T> Drop for Slice<'_, '_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            scm_array_handle_release(&raw mut self.handle);
+        }
+    }
+}
+impl<T> Deref for Slice<'_, '_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+/// A mutably borrowed, contiguous view of a [Vector]'s elements; see [Vector::as_mut_slice].
+pub struct SliceMut<'a, 'gm, T> {
+    handle: scm_t_array_handle,
+    ptr: *mut T,
+    len: usize,
+    _marker: PhantomData<&'a &'gm mut [T]>,
+}
+impl<T> Drop for SliceMut<'_, '_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            scm_array_handle_release(&raw mut self.handle);
+        }
+    }
+}
+impl<T> Deref for SliceMut<'_, '_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+impl<T> DerefMut for SliceMut<'_, '_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
 /// Iterator for [Vector::into_iter].
 pub struct IntoIter<'gm, T>
 where
@@ -377,9 +793,13 @@ where
     T: TryFromScm<'gm>,
 {
     handle: scm_t_array_handle,
+    vector: SCM,
     ptr: *mut SCM,
     step: isize,
     len: Option<NonZeroUsize>,
+    /// Number of elements already yielded from the front; together with the remaining `len`,
+    /// gives the absolute index of whichever element `next`/`next_back` yields next.
+    front: usize,
     _marker: PhantomData<&'a &'gm T>,
 }
 impl<'gm, T> DoubleEndedIterator for IterMut<'_, 'gm, T>
@@ -391,8 +811,9 @@ where
             (ptr, Some(len)) if !ptr.is_null() => {
                 let len = len.get() - 1;
                 self.len = NonZeroUsize::new(len);
+                let index = self.front + len;
                 let ptr = unsafe { ptr.offset(isize::try_from(len).unwrap() * self.step) };
-                Some(unsafe { RefMut::new_unchecked(ptr.read()) })
+                Some(unsafe { RefMut::with_writer(ptr.read(), write_elem, self.vector, index) })
             }
             _ => None,
         }
@@ -421,8 +842,10 @@ where
             (ptr, Some(len)) if !ptr.is_null() => {
                 self.ptr = unsafe { self.ptr.offset(self.step) };
                 self.len = NonZeroUsize::new(len.get() - 1);
+                let index = self.front;
+                self.front += 1;
 
-                Some(unsafe { RefMut::new_unchecked(ptr.read()) })
+                Some(unsafe { RefMut::with_writer(ptr.read(), write_elem, self.vector, index) })
             }
             _ => None,
         }
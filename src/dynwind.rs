@@ -14,11 +14,22 @@
 // limitations under the License.
 
 //! Ensure calls to drop in case of stack unwinding.
+//!
+//! [Dynwind::rewindable_scope] plus [Dynwind::rewind_handler]/[Dynwind::guard] (passing
+//! `SCM_F_DYNWIND_REWINDABLE`/registering a generic trampoline over `scm_dynwind_rewind_handler`,
+//! respectively) already cover continuation re-entry; [Dynwind::defer] covers a one-shot
+//! arbitrary closure on unwind. `Fn` rather than `FnMut` is required throughout because a
+//! captured continuation may re-enter the same registration while an earlier call to it is still
+//! on the stack, which an `FnMut` invoked through a raw pointer could not survive without
+//! aliasing `&mut`; reach for [`Cell`][std::cell::Cell]/[`RefCell`][std::cell::RefCell] instead.
 
 use {
     crate::{
         Guile,
-        sys::{scm_dynwind_begin, scm_dynwind_end, scm_dynwind_unwind_handler},
+        sys::{
+            SCM_F_DYNWIND_REWINDABLE, SCM_F_WIND_EXPLICITLY, scm_dynwind_begin, scm_dynwind_end,
+            scm_dynwind_rewind_handler, scm_dynwind_unwind_handler, scm_t_dynwind_flags,
+        },
     },
     std::{ffi::c_void, marker::PhantomData, pin::Pin, ptr},
 };
@@ -32,9 +43,9 @@ impl<'gm> Dynwind<'gm> {
     /// # Safety
     ///
     /// [Self::drop] must be ran, unless you abort.
-    unsafe fn new(_: &'gm Guile) -> Self {
+    unsafe fn new_unchecked(flags: scm_t_dynwind_flags, _: &'gm Guile) -> Self {
         unsafe {
-            scm_dynwind_begin(0);
+            scm_dynwind_begin(flags);
         }
 
         Self {
@@ -60,6 +71,153 @@ impl Dynwind<'_> {
         ptr
     }
 }
+unsafe extern "C" fn call_boxed_fn_once<F>(ptr: *mut c_void)
+where
+    F: FnOnce(),
+{
+    let f = unsafe { Box::from_raw(ptr.cast::<F>()) };
+    f();
+}
+/// Owns the closure registered by [Dynwind::defer] until either it's run by the unwind handler
+/// or this guard is dropped normally.
+///
+/// Dropping this normally means the scope was left without a non-local exit, so the closure must
+/// *not* run; the box is reclaimed without calling it.
+pub struct DeferGuard<F>(*mut F);
+impl<F> Drop for DeferGuard<F> {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(self.0) });
+    }
+}
+impl Dynwind<'_> {
+    /// Defer an arbitrary cleanup closure to run only if this scope is left through a non-local
+    /// exit (e.g. a continuation invocation or [Guile::throw][crate::Guile::throw]).
+    ///
+    /// Unlike [Self::protect], this doesn't require a dedicated [Drop] type, so it can protect
+    /// resources that aren't tied to a value, like releasing a lock or reverting a flag. The
+    /// returned [DeferGuard] must be kept alive for the remainder of the scope; dropping it is
+    /// what reclaims the closure on the normal, non-unwinding path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::list::List, dynwind::Dynwind, symbol::Symbol, Guile, with_guile};
+    /// # use std::sync::atomic::{self, AtomicBool};
+    /// # #[cfg(not(miri))] {
+    /// static REVERTED: AtomicBool = AtomicBool::new(false);
+    /// fn test_defer<F>(f: F, unwind: bool)
+    /// where
+    ///     F: FnOnce(&Guile),
+    /// {
+    ///     REVERTED.store(false, atomic::Ordering::Release);
+    ///     assert_eq!(
+    ///         with_guile(|guile| {
+    ///             Dynwind::scope(|wind| {
+    ///                 let _guard = wind.defer(|| REVERTED.store(true, atomic::Ordering::Release));
+    ///                 f(guile)
+    ///             }, guile)
+    ///         })
+    ///         .is_none(),
+    ///         unwind
+    ///     );
+    ///     assert_eq!(REVERTED.load(atomic::Ordering::Acquire), unwind);
+    /// }
+    /// test_defer(|guile| guile.throw(Symbol::from_str("intentional-error", guile), List::<i32>::new(guile)), true);
+    /// test_defer(|_| {}, false);
+    /// # }
+    /// ```
+    pub fn defer<F>(&self, f: F) -> DeferGuard<F>
+    where
+        F: FnOnce(),
+    {
+        let ptr = Box::into_raw(Box::new(f));
+        unsafe {
+            scm_dynwind_unwind_handler(Some(call_boxed_fn_once::<F>), ptr.cast::<c_void>(), 0);
+        }
+        DeferGuard(ptr)
+    }
+}
+unsafe extern "C" fn call_fn<F>(ptr: *mut c_void)
+where
+    F: Fn(),
+{
+    unsafe { (*ptr.cast::<F>())() }
+}
+impl Dynwind<'_> {
+    /// Register a rewind handler: run once immediately, and again every time a captured
+    /// continuation re-enters this scope.
+    ///
+    /// # Safety
+    ///
+    /// `f` may be called more than once for a single registration, so it must not capture `&mut`
+    /// Rust state by value; reach for interior mutability (e.g. [Cell][std::cell::Cell]) instead.
+    /// Requiring [Fn] rather than [FnMut]/[FnOnce] enforces this at the type level.
+    ///
+    /// More importantly, a continuation captured inside this scope can re-enter it after the Rust
+    /// call frame that created `f` has already returned (guile continuations aren't bound by
+    /// Rust's call stack), invoking `f` over what would otherwise be freed/invalidated memory.
+    /// `f` must stay valid for as long as any continuation captured inside this scope remains
+    /// reachable, which this function has no way to bound on its own — the `'a` tying `f` to
+    /// `self` only guarantees it outlives this *call*, not every future re-entry.
+    pub unsafe fn rewind_handler<'a, F>(&'a self, f: &'a F)
+    where
+        F: Fn(),
+    {
+        unsafe {
+            scm_dynwind_rewind_handler(
+                Some(call_fn::<F>),
+                ptr::from_ref(f).cast_mut().cast::<c_void>(),
+                SCM_F_WIND_EXPLICITLY,
+            );
+        }
+    }
+
+    /// Register a paired before/after action, mirroring the full before/after semantics of
+    /// Scheme's `dynamic-wind`: `enter` runs on every (re-)entry into this scope (via
+    /// [Self::rewind_handler]) and `exit` runs on every exit, whether normal or driven by a
+    /// continuation jumping back out.
+    ///
+    /// # Safety
+    ///
+    /// See [Self::rewind_handler]; the same constraints apply to `exit`, since it may also run
+    /// more than once, over a registration that can outlive the Rust call frame that created it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{dynwind::Dynwind, with_guile};
+    /// # use std::cell::Cell;
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let depth = Cell::new(0);
+    ///     let enter = || depth.set(depth.get() + 1);
+    ///     let exit = || depth.set(depth.get() - 1);
+    ///     Dynwind::rewindable_scope(|wind| {
+    ///         unsafe {
+    ///             wind.guard(&enter, &exit);
+    ///         }
+    ///         assert_eq!(depth.get(), 1);
+    ///     }, guile);
+    ///     assert_eq!(depth.get(), 0);
+    /// }).unwrap();
+    /// ```
+    pub unsafe fn guard<'a, Enter, Exit>(&'a self, enter: &'a Enter, exit: &'a Exit)
+    where
+        Enter: Fn(),
+        Exit: Fn(),
+    {
+        unsafe {
+            self.rewind_handler(enter);
+        }
+        unsafe {
+            scm_dynwind_unwind_handler(
+                Some(call_fn::<Exit>),
+                ptr::from_ref(exit).cast_mut().cast::<c_void>(),
+                SCM_F_WIND_EXPLICITLY,
+            );
+        }
+    }
+}
 impl<'gm> Dynwind<'gm> {
     /// Establish a scope where you can protect objects from guile unwinding.
     ///
@@ -103,7 +261,19 @@ impl<'gm> Dynwind<'gm> {
     where
         F: FnOnce(&Self) -> O,
     {
-        let dynwind = unsafe { Self::new(guile) };
+        let dynwind = unsafe { Self::new_unchecked(0, guile) };
+        f(&dynwind)
+    }
+
+    /// Establish a scope like [Self::scope], but pass [SCM_F_DYNWIND_REWINDABLE] so a continuation
+    /// captured inside may re-enter it after it has already run to completion, rather than that
+    /// re-entry being rejected. Required for [Self::rewind_handler]/[Self::guard] to be reachable
+    /// more than once.
+    pub fn rewindable_scope<F, O>(f: F, guile: &'gm Guile) -> O
+    where
+        F: FnOnce(&Self) -> O,
+    {
+        let dynwind = unsafe { Self::new_unchecked(SCM_F_DYNWIND_REWINDABLE, guile) };
         f(&dynwind)
     }
 }
@@ -83,4 +83,50 @@ impl Guile {
             self,
         )
     }
+
+    /// # Safety
+    ///
+    /// See [Self::eval].
+    ///
+    /// # Exceptions
+    ///
+    /// Unlike [Self::eval], a condition thrown by `str` is caught via [Self::catch_scm] and
+    /// returned as `Err(Scm<'gm>)` (the `(key . args)` pair) instead of unwinding past this
+    /// frame, so evaluation composes with ordinary [Result] handling.
+    ///
+    /// # Examples
+    /// ```
+    /// # use garguile::{string::String, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(unsafe { guile.try_eval::<i32>(&String::from_str("(+ 1 2)", guile)) }, Ok(3));
+    ///     assert!(unsafe { guile.try_eval::<i32>(&String::from_str("(error \"boom\")", guile)) }.is_err());
+    /// }).unwrap();
+    /// ```
+    pub unsafe fn try_eval<'gm, T>(&'gm self, str: &String<'gm>) -> Result<T, Scm<'gm>>
+    where
+        T: TryFromScm<'gm>,
+    {
+        self.catch_scm(|guile| unsafe { guile.eval(str) })
+            .and_then(|result| result)
+    }
+
+    /// # Safety
+    ///
+    /// See [Self::eval].
+    ///
+    /// # Exceptions
+    ///
+    /// See [Self::try_eval].
+    pub unsafe fn try_eval_in<'gm, T>(
+        &'gm self,
+        str: &String<'gm>,
+        module: &Module<'gm>,
+    ) -> Result<T, Scm<'gm>>
+    where
+        T: TryFromScm<'gm>,
+    {
+        self.catch_scm(|guile| unsafe { guile.eval_in(str, module) })
+            .and_then(|result| result)
+    }
 }
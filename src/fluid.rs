@@ -0,0 +1,182 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Scoped dynamic bindings, mirroring Guile's fluids/parameters.
+
+use {
+    crate::{
+        Guile,
+        scm::{Scm, ToScm, TryFromScm},
+        sys::{SCM, SCM_UNDEFINED, scm_c_with_fluid, scm_fluid_ref, scm_make_fluid_with_default},
+    },
+    std::{ffi::c_void, marker::PhantomData},
+};
+
+struct CallbackData<F, T> {
+    thunk: Option<F>,
+    output: Option<T>,
+}
+
+/// # Safety
+///
+/// `data` must be a pointer of type `CallbackData<F, T>`
+unsafe extern "C" fn with_fluid_callback<F, T>(data: *mut c_void) -> SCM
+where
+    F: FnOnce() -> T,
+{
+    if let Some(CallbackData { thunk, output }) =
+        unsafe { data.cast::<CallbackData<F, T>>().as_mut() }
+    {
+        *output = thunk.take().map(|thunk| thunk());
+    }
+
+    unsafe { SCM_UNDEFINED }
+}
+
+/// A dynamically-scoped binding: the value nested Scheme (and Rust) frames observe through
+/// [Self::get] while a [Self::with_fluid] scope is active, restored once that scope ends, even if
+/// it's left through a non-local exit, since `scm_c_with_fluid` establishes the binding with
+/// Guile's own `dynamic-wind`.
+pub struct Fluid<'gm, T> {
+    scm: SCM,
+    _marker: PhantomData<(&'gm (), fn() -> T)>,
+}
+impl<'gm, T> Fluid<'gm, T>
+where
+    T: ToScm<'gm> + TryFromScm<'gm>,
+{
+    /// Create a fluid holding `default` until a [Self::with_fluid] scope overrides it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{fluid::Fluid, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let fluid = Fluid::new(10, guile);
+    ///     assert_eq!(fluid.get(guile), 10);
+    /// }).unwrap();
+    /// ```
+    pub fn new(default: T, guile: &'gm Guile) -> Self {
+        Self {
+            scm: unsafe { scm_make_fluid_with_default(default.to_scm(guile).as_ptr()) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// The fluid's current dynamic value.
+    pub fn get(&self, guile: &'gm Guile) -> T {
+        // SAFETY: every value ever bound to this fluid was put there by `Self::new` or
+        // `Self::with_fluid`, both of which only accept `T`.
+        unsafe { T::from_scm_unchecked(Scm::from_ptr_unchecked(scm_fluid_ref(self.scm)), guile) }
+    }
+
+    /// Run `f` with this fluid rebound to `value` for the duration of the call, restoring the
+    /// previous value when `f` returns, throws, or is left via a captured continuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{fluid::Fluid, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let fluid = Fluid::new(10, guile);
+    ///     assert_eq!(fluid.with_fluid(20, || fluid.get(guile)), 20);
+    ///     assert_eq!(fluid.get(guile), 10);
+    /// }).unwrap();
+    /// ```
+    pub fn with_fluid<F, R>(&self, value: T, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        // SAFETY: having a [Self] exist is proof of being in guile mode.
+        let guile = unsafe { Guile::new_unchecked() };
+        let mut data = CallbackData::<F, R> {
+            thunk: Some(f),
+            output: None,
+        };
+
+        unsafe {
+            scm_c_with_fluid(
+                self.scm,
+                value.to_scm(&guile).as_ptr(),
+                Some(with_fluid_callback::<F, R>),
+                (&raw mut data).cast(),
+            );
+        }
+
+        data.output
+            .expect("scm_c_with_fluid should always invoke the callback")
+    }
+}
+
+/// A [Fluid] that runs every value it's bound to through a `converter` first, mirroring the
+/// converter procedure Guile's `make-parameter` accepts.
+pub struct Parameter<'gm, T, C> {
+    fluid: Fluid<'gm, T>,
+    converter: C,
+}
+impl<'gm, T, C> Parameter<'gm, T, C>
+where
+    T: ToScm<'gm> + TryFromScm<'gm>,
+    C: Fn(T) -> T,
+{
+    /// Create a parameter holding `converter(default)` until a [Self::with_fluid] scope
+    /// overrides it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{fluid::Parameter, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let param = Parameter::new(10, i32::abs, guile);
+    ///     assert_eq!(param.get(guile), 10);
+    /// }).unwrap();
+    /// ```
+    pub fn new(default: T, converter: C, guile: &'gm Guile) -> Self {
+        Self {
+            fluid: Fluid::new(converter(default), guile),
+            converter,
+        }
+    }
+
+    /// The parameter's current dynamic value.
+    pub fn get(&self, guile: &'gm Guile) -> T {
+        self.fluid.get(guile)
+    }
+
+    /// Run `f` with this parameter rebound to `converter(value)` for the duration of the call,
+    /// restoring the previous value when `f` returns, throws, or is left via a captured
+    /// continuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{fluid::Parameter, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let param = Parameter::new(10, i32::abs, guile);
+    ///     assert_eq!(param.with_fluid(-20, || param.get(guile)), 20);
+    ///     assert_eq!(param.get(guile), 10);
+    /// }).unwrap();
+    /// ```
+    pub fn with_fluid<F, R>(&self, value: T, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.fluid.with_fluid((self.converter)(value), f)
+    }
+}
@@ -20,13 +20,20 @@ use {
         Guile,
         collections::list::List,
         reference::ReprScm,
-        scm::ToScm,
+        scm::{Scm, ToScm},
         symbol::Symbol,
-        sys::{SCM, scm_unused_struct},
+        sys::{
+            SCM, scm_foreign_object_ref, scm_make_foreign_object_1, scm_make_foreign_object_type,
+            scm_unused_struct,
+        },
     },
-    std::sync::{
-        LazyLock,
-        atomic::{self, AtomicPtr},
+    std::{
+        any::TypeId,
+        collections::HashMap,
+        sync::{
+            LazyLock, Mutex,
+            atomic::{self, AtomicPtr},
+        },
     },
 };
 
@@ -50,6 +57,57 @@ pub unsafe fn slots() -> SCM {
     SYMBOL.load(atomic::Ordering::Acquire)
 }
 
+/// Turn a Rust type name (e.g. `crate::Wrapper<i32>`, from [std::any::type_name]) into something
+/// safe to embed in a Guile symbol: lowercase ASCII, with every run of characters that aren't
+/// alphanumeric collapsed to a single `-`.
+fn mangle(type_name: &str) -> String {
+    let mut mangled = String::with_capacity(type_name.len());
+    let mut last_was_sep = true;
+    for ch in type_name.chars() {
+        if ch.is_ascii_alphanumeric() {
+            mangled.push(ch.to_ascii_lowercase());
+            last_was_sep = false;
+        } else if !last_was_sep {
+            mangled.push('-');
+            last_was_sep = true;
+        }
+    }
+    mangled.truncate(mangled.trim_end_matches('-').len());
+    mangled
+}
+
+/// Get or create the Guile type tag for `T`, with a distinct type per monomorphization.
+///
+/// A `static` declared inside a generic function is one shared item across every instantiation,
+/// not monomorphized per type argument, so caching `T`'s type tag in a plain `static` inside
+/// [ForeignObject::get_or_create_type] would make e.g. `Wrapper<i32>` and `Wrapper<String>`
+/// collide on the same Guile type and the same name — [crate::scm::TryFromScm::predicate] could
+/// no longer tell them apart. Keying a single shared cache on `T`'s [TypeId] instead, and folding
+/// `T`'s concrete name into the Guile type's name, keeps every monomorphization distinct.
+///
+/// This is only exported for the `ForeignObject` derive macro.
+///
+/// # Safety
+///
+/// Only call in guile mode.
+#[doc(hidden)]
+pub unsafe fn get_or_create_type<T: 'static>(name: &str) -> SCM {
+    static TYPES: LazyLock<Mutex<HashMap<TypeId, AtomicPtr<scm_unused_struct>>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    TYPES
+        .lock()
+        .unwrap()
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| {
+            let guile = unsafe { Guile::new_unchecked_ref() };
+            let name = format!("{name}-{}", mangle(std::any::type_name::<T>()));
+            let name = Symbol::from_str(&name, guile);
+            unsafe { scm_make_foreign_object_type(ReprScm::as_ptr(&name), slots(), None) }.into()
+        })
+        .load(atomic::Ordering::Acquire)
+}
+
 /// Custom types that can be used in guile.
 pub trait ForeignObject: Copy + Send + Sync {
     /// Create a type tag.
@@ -60,3 +118,126 @@ pub trait ForeignObject: Copy + Send + Sync {
     unsafe fn get_or_create_type() -> SCM;
 }
 pub use garguile_proc_macros::ForeignObject;
+
+/// Get or create the Guile type tag for a [Finalized] `T`, with a distinct type per
+/// monomorphization (see [get_or_create_type]'s doc comment for why that requires keying on
+/// [TypeId] rather than a plain generic `static`).
+///
+/// Unlike [get_or_create_type], this registers [finalize::<T>] as the type's finalizer, so it gets
+/// its own cache even for a `T` that also happens to implement [ForeignObject] — the two paths
+/// store data differently (GC-allocated and copied out vs. boxed and finalized) and must not be
+/// mixed for the same underlying Guile type.
+///
+/// This is only exported for the `Finalized` derive macro.
+///
+/// # Safety
+///
+/// Only call in guile mode.
+#[doc(hidden)]
+pub unsafe fn get_or_create_finalized_type<T: 'static>(name: &str) -> SCM {
+    static TYPES: LazyLock<Mutex<HashMap<TypeId, AtomicPtr<scm_unused_struct>>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    TYPES
+        .lock()
+        .unwrap()
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| {
+            let guile = unsafe { Guile::new_unchecked_ref() };
+            let name = format!("{name}-{}", mangle(std::any::type_name::<T>()));
+            let name = Symbol::from_str(&name, guile);
+            unsafe {
+                scm_make_foreign_object_type(ReprScm::as_ptr(&name), slots(), Some(finalize::<T>))
+            }
+            .into()
+        })
+        .load(atomic::Ordering::Acquire)
+}
+
+/// Finalizer registered by [get_or_create_finalized_type]; reconstructs the `Box<T>` stashed in the
+/// foreign object's `data` slot by [Finalized::into_scm] and drops it.
+///
+/// Guile's conservative GC can run a finalizer on any thread, at any point after the wrapper
+/// becomes unreachable, which is why [Finalized] requires `Send`: whatever runs in `T`'s `Drop`
+/// must be safe to run there. The finalizer must never call back into Guile — by the time it runs,
+/// the object it was attached to may already be gone.
+extern "C" fn finalize<T>(obj: SCM) {
+    let ptr = unsafe { scm_foreign_object_ref(obj, 0) }.cast::<T>();
+    if !ptr.is_null() {
+        drop(unsafe { Box::from_raw(ptr) });
+    }
+}
+
+/// Custom types that own non-[Copy] Rust data (a `Box<T>`, `Vec<T>`, file handle, ...) inside a
+/// Guile value, with `Drop` running when Guile's GC reclaims the wrapper.
+///
+/// [ForeignObject]'s storage lives in [`crate::alloc::GcAllocator`]'d memory that Guile's GC frees
+/// outright without running any destructor, which is fine for `Copy` data (nothing to run) but
+/// loses owned resources silently otherwise. `Finalized` instead boxes `self` on the ordinary Rust
+/// heap and registers a finalizer (see [finalize]) to drop that box, so `Self` only needs
+/// `Send + Sync` rather than `Copy`. The two traits are independent; implement whichever one's
+/// storage strategy fits `Self`.
+pub trait Finalized: Send + Sync + Sized + 'static {
+    /// Create a type tag, registering [finalize::<Self>] to run when Guile's GC reclaims an
+    /// instance.
+    ///
+    /// # Safety
+    ///
+    /// Only call in guile mode.
+    unsafe fn get_or_create_finalized_type() -> SCM;
+
+    /// Move `self` into a freshly boxed Guile value; the box is dropped by [finalize] once Guile's
+    /// GC reclaims the returned [Scm].
+    fn into_scm<'gm>(self, guile: &'gm Guile) -> Scm<'gm> {
+        let ptr = Box::into_raw(Box::new(self));
+        Scm::from_ptr(
+            unsafe { scm_make_foreign_object_1(Self::get_or_create_finalized_type(), ptr.cast()) },
+            guile,
+        )
+    }
+
+    /// Borrow the `Self` owned by `scm`.
+    ///
+    /// # Safety
+    ///
+    /// `scm` must have been produced by [Self::into_scm] (or still hold a live instance of this
+    /// exact monomorphization); callers typically guard this with a
+    /// [`TryFromScm::predicate`][crate::scm::TryFromScm::predicate]-style check first.
+    unsafe fn from_scm<'a>(scm: &Scm<'a>) -> &'a Self {
+        let ptr = unsafe { scm_foreign_object_ref(ReprScm::as_ptr(scm), 0) }.cast::<Self>();
+        unsafe { &*ptr }
+    }
+}
+pub use garguile_proc_macros::Finalized;
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{gc, with_guile},
+        std::sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Finalized)]
+    struct DropCounter;
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            DROPPED.fetch_add(1, Ordering::Release);
+        }
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn finalizer_runs_after_gc() {
+        with_guile(|guile| {
+            DROPPED.store(0, Ordering::Release);
+            DropCounter.into_scm(guile);
+            // The scm above is now unreachable from Rust; a forced collection should finalize it.
+            gc::force(guile);
+            assert_eq!(DROPPED.load(Ordering::Acquire), 1);
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `arbitrary`-driven roundtrip checking for [`ToScm`]/[`TryFromScm`]: build a random `T`, send it
+//! through Scheme and back, and assert it comes back unchanged. Feature-gated behind `arbitrary`,
+//! which (like the `bigint` feature in [`crate::num`]) has no `Cargo.toml` in this tree to actually
+//! register the dependency — this module is written as though it were, for `cargo fuzz` targets
+//! under `fuzz/fuzz_targets/` to call into.
+//!
+//! [`roundtrip`] covers every `T` whose equality is structural (tuples, [`String`][crate::string],
+//! and similar). `f64` is the one exception: `NaN != NaN`, so a generic `PartialEq`-based check
+//! would flag every roundtripped `NaN` as a failure even when the bits came back identical.
+//! [`roundtrip_f64`] compares `to_bits()` instead.
+
+use {
+    crate::{
+        scm::{ToScm, TryFromScm},
+        with_guile,
+    },
+    arbitrary::{Arbitrary, Unstructured},
+};
+
+/// Build a `T` from `data`, send it through `to_scm` then `try_from_scm`, and assert the result
+/// equals the original.
+///
+/// `T` is built from `data` twice (once to consume via [`ToScm::to_scm`], once kept aside to
+/// compare against, since `to_scm` takes `self` by value and `T` isn't required to be [`Clone`])
+/// rather than once, relying on `arbitrary::Arbitrary` being a pure function of its input bytes.
+/// Does nothing if `data` is too short for `T::arbitrary_take_rest` to produce a value.
+///
+/// `T` must round-trip for *every* `'gm`, which rules out types like
+/// [`List<'gm, _>`][crate::collections::list::List] that are themselves indexed by the session
+/// lifetime; those need a dedicated harness (see `fuzz/fuzz_targets/list.rs`) rather than going
+/// through this generic helper.
+pub fn roundtrip<T>(data: &[u8])
+where
+    T: for<'a> Arbitrary<'a> + for<'gm> ToScm<'gm> + for<'gm> TryFromScm<'gm> + PartialEq,
+{
+    let (Ok(to_send), Ok(expected)) = (
+        T::arbitrary_take_rest(Unstructured::new(data)),
+        T::arbitrary_take_rest(Unstructured::new(data)),
+    ) else {
+        return;
+    };
+
+    with_guile(|guile| {
+        let scm = to_send.to_scm(guile);
+        let recovered = T::try_from_scm(scm, guile)
+            .unwrap_or_else(|_| panic!("{:?} should round-trip through scheme", T::type_name()));
+        assert!(recovered == expected);
+    });
+}
+
+/// Like [`roundtrip`], but for `f64`: `NaN != NaN`, so this compares `to_bits()` instead of the
+/// `f64` itself.
+pub fn roundtrip_f64(data: &[u8]) {
+    let (Ok(to_send), Ok(expected)) = (
+        f64::arbitrary_take_rest(Unstructured::new(data)),
+        f64::arbitrary_take_rest(Unstructured::new(data)),
+    ) else {
+        return;
+    };
+
+    with_guile(|guile| {
+        let scm = to_send.to_scm(guile);
+        let recovered = f64::try_from_scm(scm, guile)
+            .unwrap_or_else(|_| panic!("f64 should round-trip through scheme"));
+        assert_eq!(recovered.to_bits(), expected.to_bits());
+    });
+}
@@ -0,0 +1,127 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An owned handle that outlives `'gm`.
+
+use {
+    crate::{
+        Guile,
+        reference::ReprScm,
+        sys::{SCM, scm_gc, scm_gc_protect_object, scm_gc_unprotect_object},
+    },
+    std::{marker::PhantomData, mem},
+};
+
+/// Force an immediate full collection, via Guile's `scm_gc` (what `(gc)` calls in Scheme).
+///
+/// # Examples
+///
+/// ```
+/// # use garguile::{gc, with_guile};
+/// # #[cfg(not(miri))]
+/// with_guile(|guile| gc::force(guile)).unwrap();
+/// ```
+pub fn force(_: &Guile) {
+    unsafe {
+        scm_gc();
+    }
+}
+
+/// An owned, refcounted handle to a `T`, kept alive by [`scm_gc_protect_object`] rather than a
+/// `'gm` borrow.
+///
+/// Every collection in this crate is tied to a `&'gm Guile`, so its values cannot be stored in a
+/// long-lived Rust struct, moved into another thread's later call, or cached between
+/// [`with_guile`][crate::with_guile] invocations. [`Gc`] is the escape hatch: it takes ownership
+/// of the underlying `SCM`, protecting it from collection for as long as this handle (and any
+/// clone of it) is alive, regardless of `'gm`.
+///
+/// There's deliberately no `Deref<Target = T>` here: `T` is only sound to read back for as long
+/// as the `'gm` session that produced it is still live, and `Deref` has no lifetime parameter to
+/// carry that borrow through. [`Self::borrow`] is the `Deref`-shaped alternative that re-attaches
+/// a fresh `'g` instead of assuming the original one still holds.
+#[repr(transparent)]
+pub struct Gc<T> {
+    ptr: SCM,
+    _marker: PhantomData<T>,
+}
+impl<T> Gc<T>
+where
+    T: ReprScm,
+{
+    /// Take ownership of `value`, protecting it from collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{gc::Gc, scm::ToScm, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let gc = Gc::new(1.to_scm(guile), guile);
+    ///     assert_eq!(*gc.borrow(guile), 1.to_scm(guile));
+    /// }).unwrap();
+    /// ```
+    pub fn new(value: T, _: &Guile) -> Self {
+        let ptr = value.as_ptr();
+        unsafe {
+            scm_gc_protect_object(ptr);
+        }
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Re-attach a lifetime to the held value, borrowing it for as long as `guile` is live.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{gc::Gc, scm::ToScm, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let gc = Gc::new(1.to_scm(guile), guile);
+    ///     with_guile(|guile| {
+    ///         assert_eq!(*gc.borrow(guile), 1.to_scm(guile));
+    ///     }).unwrap();
+    /// }).unwrap();
+    /// ```
+    pub fn borrow<'g>(&self, _: &'g Guile) -> &'g T {
+        unsafe { mem::transmute(self) }
+    }
+}
+impl<T> Clone for Gc<T>
+where
+    T: ReprScm,
+{
+    /// Re-protect the same underlying value, producing an independent handle; the value is
+    /// only released once every handle derived this way has been dropped.
+    fn clone(&self) -> Self {
+        unsafe {
+            scm_gc_protect_object(self.ptr);
+        }
+        Self {
+            ptr: self.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+impl<T> Drop for Gc<T> {
+    fn drop(&mut self) {
+        unsafe {
+            scm_gc_unprotect_object(self.ptr);
+        }
+    }
+}
@@ -16,12 +16,15 @@
 use {
     crate::{
         Guile,
-        sys::{scm_with_guile, scm_without_guile},
+        sys::{scm_init_guile, scm_with_guile, scm_without_guile},
     },
     parking_lot::Mutex,
     std::{
+        cell::Cell,
         ffi::c_void,
         marker::PhantomData,
+        ops::{Deref, DerefMut},
+        ptr,
         sync::atomic::{self, AtomicBool},
     },
 };
@@ -30,6 +33,48 @@ static INIT_LOCK: Mutex<()> = Mutex::new(());
 thread_local! {
     static INIT: AtomicBool = const { AtomicBool::new(false) };
     static GUILE_MODE: AtomicBool = const { AtomicBool::new(false) };
+    /// The [Guile] token handed to the innermost active [with_guile]/[Guile::block_on] scope on
+    /// this thread, borrowed from [environmental](https://docs.rs/environmental). Dereferencing it
+    /// is only sound from within that scope, which is exactly where [Guile::with_current] runs it.
+    static CONTEXT: Cell<*const Guile> = const { Cell::new(ptr::null()) };
+}
+
+/// Saves the previous ambient [Guile] context on construction and restores it on drop, so nested
+/// scopes (including ones unwound via panics) can't leave a dangling pointer behind for a parent
+/// scope to observe.
+struct ContextGuard(*const Guile);
+impl ContextGuard {
+    fn new(current: *const Guile) -> Self {
+        Self(CONTEXT.with(|context| context.replace(current)))
+    }
+}
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| context.set(self.0));
+    }
+}
+impl Guile {
+    /// Recover the ambient [Guile] context stashed by the innermost enclosing
+    /// [with_guile]/[Guile::block_on] scope on this thread, or run nothing and return [None] if
+    /// called outside of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{Guile, with_guile};
+    /// assert_eq!(Guile::with_current(|_| ()), None);
+    /// # #[cfg(not(miri))]
+    /// with_guile(|_| {
+    ///     assert!(Guile::with_current(|_| ()).is_some());
+    /// }).unwrap();
+    /// ```
+    pub fn with_current<F, O>(f: F) -> Option<O>
+    where
+        F: FnOnce(&Guile) -> O,
+    {
+        ptr::NonNull::new(CONTEXT.with(|context| context.get()).cast_mut())
+            .map(|guile| f(unsafe { guile.as_ref() }))
+    }
 }
 
 struct CallbackData<T>
@@ -73,7 +118,8 @@ unsafe trait GuileModeToggle {
         std::ptr::null_mut()
     }
     fn toggle(morphism: Self::Fn) -> Option<Self::Output> {
-        if GUILE_MODE.with(|mode| mode.load(atomic::Ordering::Acquire)) == Self::GUILE_MODE_STATUS {
+        let previous = GUILE_MODE.with(|mode| mode.load(atomic::Ordering::Acquire));
+        if previous == Self::GUILE_MODE_STATUS {
             Some(unsafe { Self::eval(morphism) })
         } else {
             let _lock = (!INIT.with(|init| init.load(atomic::Ordering::Acquire))
@@ -88,7 +134,11 @@ unsafe trait GuileModeToggle {
 
             unsafe { Self::SCOPE(Some(Self::callback), (&raw mut data).cast()) };
 
-            GUILE_MODE.with(|mode| mode.store(!Self::GUILE_MODE_STATUS, atomic::Ordering::Release));
+            // Restore the value observed on entry (dynamic scoping, as `environmental` does for
+            // thread-local reference variables), rather than hardcoding `!GUILE_MODE_STATUS`: the
+            // latter only happens to agree with the saved value because `GUILE_MODE` is currently a
+            // bare bool, and silently stops matching it the moment a third toggle state is added.
+            GUILE_MODE.with(|mode| mode.store(previous, atomic::Ordering::Release));
             data.output
         }
     }
@@ -114,7 +164,9 @@ where
     ) -> *mut c_void = scm_with_guile;
 
     unsafe fn eval(f: Self::Fn) -> Self::Output {
-        f(&mut unsafe { Guile::new_unchecked() })
+        let mut guile = unsafe { Guile::new_unchecked() };
+        let _context = ContextGuard::new(&raw const guile);
+        f(&mut guile)
     }
 }
 
@@ -143,6 +195,75 @@ where
     WithGuile::toggle(f)
 }
 
+/// An RAII handle produced by [Guile::enter], permanently registering the current thread with
+/// Guile's GC.
+///
+/// Unlike [with_guile], which only holds the thread in guile mode for the duration of a closure,
+/// `scm_init_guile` has no matching "leave" call: once a thread is registered, it stays registered
+/// for the rest of its life. Dropping a `GuileGuard` is therefore a deliberate no-op rather than an
+/// unwind of guile mode; it merely marks the end of the scope that was borrowing the [Guile] token.
+///
+/// This exists for code that needs to hold `&mut Guile` across `.await` points or otherwise can't
+/// fit inside a single closure, e.g. an async executor's task. Prefer [with_guile] whenever a
+/// closure-shaped scope works, since it composes with nested `with_guile`/[Guile::block_on] calls
+/// and doesn't leak the thread's guile-mode registration for its remaining lifetime.
+pub struct GuileGuard {
+    guile: Guile,
+}
+impl Deref for GuileGuard {
+    type Target = Guile;
+
+    fn deref(&self) -> &Guile {
+        &self.guile
+    }
+}
+impl DerefMut for GuileGuard {
+    fn deref_mut(&mut self) -> &mut Guile {
+        &mut self.guile
+    }
+}
+impl Drop for GuileGuard {
+    /// No-op: `scm_init_guile` registers the thread for good, so there is nothing to undo here.
+    fn drop(&mut self) {}
+}
+impl Guile {
+    /// Permanently register the current thread with Guile's GC and hand back a guard holding a
+    /// [Guile] token.
+    ///
+    /// On a thread that has never entered guile mode, this calls `scm_init_guile`, which is
+    /// irreversible for that thread's lifetime; on one that's already registered (including from
+    /// inside an enclosing [with_guile]/[Guile::block_on] scope), it skips straight to handing back
+    /// a guard. Either way, dropping the returned [GuileGuard] does not leave guile mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::Guile;
+    /// # #[cfg(not(miri))]
+    /// {
+    /// let mut guile = Guile::enter();
+    /// let _sym = garguile::symbol::Symbol::from_str("foo", &mut guile);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn enter() -> GuileGuard {
+        let _lock =
+            (!INIT.with(|init| init.load(atomic::Ordering::Acquire))).then(|| INIT_LOCK.lock());
+
+        if !GUILE_MODE.with(|mode| mode.load(atomic::Ordering::Acquire)) {
+            unsafe {
+                scm_init_guile();
+            }
+            INIT.with(|init| init.store(true, atomic::Ordering::Release));
+            GUILE_MODE.with(|mode| mode.store(true, atomic::Ordering::Release));
+        }
+
+        GuileGuard {
+            guile: unsafe { Guile::new_unchecked() },
+        }
+    }
+}
+
 struct WithoutGuile<F, O>
 where
     F: FnOnce() -> O,
@@ -164,6 +285,7 @@ where
     ) -> *mut c_void = scm_without_guile;
 
     unsafe fn eval(f: Self::Fn) -> Self::Output {
+        let _context = ContextGuard::new(ptr::null());
         f()
     }
 }
@@ -189,9 +311,151 @@ impl Guile {
     }
 }
 
+/// How many items [block_on_chunked] drives through its closure between [gc_checkpoint] calls.
+const CHECKPOINT_INTERVAL: usize = 1024;
+
+/// Briefly exit and re-enter guile mode, via the same [WithoutGuile]/[WithGuile] toggle machinery
+/// [with_guile] and [Guile::block_on] use, to give a collection pending on another thread a chance
+/// to run.
+///
+/// This is cheap and a no-op when the calling thread is already outside guile mode: [GuileModeToggle::toggle]
+/// only performs the real `scm_without_guile` round trip when the thread is currently inside guile
+/// mode, so calling this from within [Guile::block_on] (or any other non-guile context) just runs
+/// the empty closure in place.
+///
+/// # Examples
+///
+/// ```
+/// # use garguile::{gc_checkpoint, with_guile};
+/// # #[cfg(not(miri))]
+/// with_guile(|_| gc_checkpoint()).unwrap();
+/// gc_checkpoint();
+/// ```
+pub fn gc_checkpoint() {
+    WithoutGuile::toggle(|| {});
+}
+
+/// Drive `iter` through `f`, calling [gc_checkpoint] every [CHECKPOINT_INTERVAL] items.
+///
+/// [Guile::block_on]'s doc notes that a long stretch without calling a guile function can block
+/// garbage collection on old guile versions; wrapping a whole CPU-bound loop in [Guile::block_on]
+/// works but forces an all-or-nothing split between guile and non-guile code. `block_on_chunked` is
+/// the finer-grained alternative: it periodically yields a checkpoint so a long-running loop
+/// cooperates with the collector without needing to restructure around a single closure boundary.
+///
+/// # Examples
+///
+/// ```
+/// # use garguile::block_on_chunked;
+/// let mut sum = 0;
+/// block_on_chunked(0..10, |i| sum += i);
+/// assert_eq!(sum, 45);
+/// ```
+pub fn block_on_chunked<I, F>(iter: I, mut f: F)
+where
+    I: IntoIterator,
+    F: FnMut(I::Item),
+{
+    for (i, item) in iter.into_iter().enumerate() {
+        f(item);
+        if (i + 1) % CHECKPOINT_INTERVAL == 0 {
+            gc_checkpoint();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use {super::*, itertools::Itertools, std::thread};
+    use {
+        super::*,
+        crate::gc,
+        itertools::Itertools,
+        std::{sync::mpsc, thread, time::Duration},
+    };
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn guile_mode_restored_on_nested_exit() {
+        fn mode() -> bool {
+            GUILE_MODE.with(|mode| mode.load(atomic::Ordering::Acquire))
+        }
+
+        assert!(!mode());
+        with_guile(|_| {
+            assert!(mode());
+            with_guile(|guile| {
+                assert!(mode());
+                guile.block_on(|| {
+                    assert!(!mode());
+                    with_guile(|_| assert!(mode())).unwrap();
+                    assert!(!mode());
+                });
+                assert!(mode());
+            })
+            .unwrap();
+            assert!(mode());
+        })
+        .unwrap();
+        assert!(!mode());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn block_on_chunked_allows_gc_to_interleave() {
+        let (tx, rx) = mpsc::channel();
+        let looper = thread::spawn(move || {
+            with_guile(|_| {
+                block_on_chunked(0..10_000_000u64, |i| {
+                    std::hint::black_box(i);
+                });
+            })
+            .unwrap();
+            tx.send(()).unwrap();
+        });
+
+        thread::spawn(|| with_guile(|guile| gc::force(guile)).unwrap())
+            .join()
+            .unwrap();
+
+        rx.recv_timeout(Duration::from_secs(5))
+            .expect("block_on_chunked should checkpoint often enough not to starve the collector");
+        looper.join().unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn enter_registers_guile_mode() {
+        fn mode() -> bool {
+            GUILE_MODE.with(|mode| mode.load(atomic::Ordering::Acquire))
+        }
+
+        thread::spawn(|| {
+            assert!(!mode());
+            let guard = Guile::enter();
+            assert!(mode());
+            drop(guard);
+            // `GuileGuard::drop` is a no-op: the thread stays registered afterward.
+            assert!(mode());
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn context_nesting() {
+        assert_eq!(Guile::with_current(|_| ()), None);
+        with_guile(|_| {
+            assert!(Guile::with_current(|_| ()).is_some());
+            assert_eq!(
+                with_guile(|guile| guile.block_on(|| Guile::with_current(|_| ()).is_none())),
+                Some(true),
+            );
+            assert!(Guile::with_current(|_| ()).is_some());
+        })
+        .unwrap();
+        assert_eq!(Guile::with_current(|_| ()), None);
+    }
 
     #[cfg_attr(miri, ignore)]
     #[test]
@@ -26,14 +26,23 @@ use {
         collections::list::List,
         reference::ReprScm,
         scm::{Scm, ToScm, TryFromScm},
-        subr::{Proc, TupleExt},
+        subr::{HookConnectFn, Proc, TupleExt},
         sys::{
-            SCM_BOOL_F, SCM_HOOK_ARITY, SCM_HOOKP, scm_add_hook_x, scm_c_run_hook,
-            scm_hook_empty_p, scm_make_hook, scm_reset_hook_x,
+            SCM, SCM_BOOL_F, SCM_BOOL_T, SCM_HOOK_ARITY, SCM_HOOKP, scm_add_hook_x,
+            scm_c_make_gsubr, scm_c_run_hook, scm_hook_empty_p, scm_hook_to_list, scm_make_hook,
+            scm_remove_hook_x, scm_reset_hook_x,
         },
+        type_name::TypeName,
         utils::{c_predicate, scm_predicate},
     },
-    std::{borrow::Cow, ffi::CStr},
+    std::{
+        ffi::c_void,
+        ptr,
+        sync::{
+            LazyLock, Mutex,
+            atomic::{self, AtomicPtr},
+        },
+    },
 };
 
 /// Procedure lists.
@@ -118,7 +127,8 @@ impl<'gm, const ARITY: usize> Hook<'gm, ARITY> {
         }
     }
 
-    /// Add a procedures to the hook.
+    /// Add a procedure to the hook, so it runs before any already registered; equivalent to
+    /// [Self::push_front].
     ///
     /// # Examples
     ///
@@ -134,12 +144,144 @@ impl<'gm, const ARITY: usize> Hook<'gm, ARITY> {
     /// }).unwrap();
     /// ```
     pub fn push(&mut self, proc: Proc<'gm>) {
+        self.push_front(proc);
+    }
+
+    /// Add a procedure to the front of the hook, so it runs before any already registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{hook::Hook, subr::{guile_fn, GuileFn}, with_guile};
+    /// #[guile_fn]
+    /// fn foo() {}
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hook = Hook::<0>::new(guile);
+    ///     hook.push_front(Foo::create(guile));
+    ///     assert!(!hook.is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn push_front(&mut self, proc: Proc<'gm>) {
         unsafe {
             let guile = Guile::new_unchecked_ref();
             scm_add_hook_x(self.0.as_ptr(), proc.to_scm(guile).as_ptr(), SCM_BOOL_F);
         }
     }
 
+    /// Add a procedure to the back of the hook, so it runs after any already registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{hook::Hook, subr::{guile_fn, GuileFn}, with_guile};
+    /// #[guile_fn]
+    /// fn foo() {}
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hook = Hook::<0>::new(guile);
+    ///     hook.push_back(Foo::create(guile));
+    ///     assert!(!hook.is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn push_back(&mut self, proc: Proc<'gm>) {
+        unsafe {
+            let guile = Guile::new_unchecked_ref();
+            scm_add_hook_x(self.0.as_ptr(), proc.to_scm(guile).as_ptr(), SCM_BOOL_T);
+        }
+    }
+
+    /// Remove a specific procedure from the hook, leaving every other registered procedure in
+    /// place. Does nothing if `proc` isn't registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{hook::Hook, subr::{guile_fn, GuileFn}, with_guile};
+    /// #[guile_fn]
+    /// fn foo() {}
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hook = Hook::<0>::new(guile);
+    ///     hook.push(Foo::create(guile));
+    ///     hook.remove(Foo::create(guile));
+    ///     assert!(hook.is_empty());
+    /// }).unwrap();
+    /// ```
+    pub fn remove(&mut self, proc: Proc<'gm>) {
+        unsafe {
+            let guile = Guile::new_unchecked_ref();
+            scm_remove_hook_x(self.0.as_ptr(), proc.to_scm(guile).as_ptr());
+        }
+    }
+
+    /// Get the procedures currently registered, in the order they would run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{hook::Hook, subr::{guile_fn, GuileFn}, with_guile};
+    /// #[guile_fn]
+    /// fn foo() {}
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut hook = Hook::<0>::new(guile);
+    ///     hook.push(Foo::create(guile));
+    ///     assert_eq!(hook.procedures().len(), 1);
+    /// }).unwrap();
+    /// ```
+    pub fn procedures(&self) -> List<'gm, Proc<'gm>> {
+        unsafe { <List<'gm, Proc<'gm>> as ReprScm>::from_ptr(scm_hook_to_list(self.0.as_ptr())) }
+    }
+
+    /// Register a Rust closure to run when the hook fires, taking its `ARITY` arguments converted
+    /// via [TryFromScm], instead of requiring a free function wrapped in
+    /// [GuileFn][crate::subr::GuileFn] and [Self::push].
+    ///
+    /// The closure is boxed and kept alive for the hook's lifetime; there's no dedicated way to
+    /// disconnect just this registration short of [Self::clear]ing the whole hook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{hook::Hook, with_guile};
+    /// # use std::sync::atomic::{self, AtomicI32};
+    /// # #[cfg(not(miri))]
+    /// {
+    /// static SUM: AtomicI32 = AtomicI32::new(0);
+    /// with_guile(|guile| {
+    ///     let mut hook = Hook::<1>::new(guile);
+    ///     hook.connect(|x: i32| {
+    ///         SUM.fetch_add(x, atomic::Ordering::Release);
+    ///     });
+    ///     hook.run((2,));
+    ///     hook.run((3,));
+    /// }).unwrap();
+    /// assert_eq!(SUM.load(atomic::Ordering::Acquire), 5);
+    /// # }
+    /// ```
+    pub fn connect<F>(&mut self, f: F)
+    where
+        F: for<'a> HookConnectFn<'a, ARITY> + Send + 'static,
+    {
+        let ptr = Box::into_raw(Box::new(Mutex::new(f))).cast::<c_void>();
+        let slot = claim_slot(ptr);
+
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let gsubr = unsafe {
+            scm_c_make_gsubr(
+                c"hook-connect-callback".as_ptr(),
+                0,
+                0,
+                1,
+                trampoline_for::<F, ARITY>(slot),
+            )
+        };
+        let proc = <Proc as TryFromScm>::try_from_scm(Scm::from_ptr(gsubr, guile), guile)
+            .expect("`scm_c_make_gsubr` should always return a procedure");
+        self.push_back(proc);
+    }
+
     /// Execute the procedures.
     ///
     /// # Examples
@@ -178,6 +320,92 @@ impl<'gm, const ARITY: usize> Hook<'gm, ARITY> {
         }
     }
 }
+
+/// Upper bound on how many [`Hook::connect`] registrations a process can make; a claimed slot is
+/// never released, not even when its hook is cleared or dropped.
+///
+/// A `scm_c_make_gsubr` callback is a bare C function pointer with no userdata slot, so the only
+/// way for [hook_connect_trampoline] to recover which boxed closure a given call belongs to is to
+/// hand each registration its own monomorphized trampoline (selected by [trampoline_for]) backed
+/// by its own slot in [CALLBACK_SLOTS], rather than one slot shared by every registration of the
+/// same (monomorphized) closure type — the latter let a second connection of an identical closure
+/// type silently replace the first one's slot, leaking its box and causing both gsubrs to invoke
+/// the most recently connected closure. Raise this (and the arm list in [trampoline_for]) if a
+/// program needs more registrations.
+const CALLBACK_SLOT_COUNT: usize = 16;
+
+/// Storage for [Hook::connect]'s boxed closures, one slot per live registration (see
+/// [CALLBACK_SLOT_COUNT]). Each occupied slot holds a `*mut Mutex<F>` erased to `c_void`; wrapping
+/// the closure in a [Mutex] means two threads invoking the same connected closure concurrently
+/// serialize on the lock instead of aliasing a bare `&mut F`.
+static CALLBACK_SLOTS: LazyLock<[AtomicPtr<c_void>; CALLBACK_SLOT_COUNT]> =
+    LazyLock::new(|| std::array::from_fn(|_| AtomicPtr::new(ptr::null_mut())));
+
+/// Claim the first free slot in [CALLBACK_SLOTS], storing `ptr` there.
+///
+/// # Panics
+///
+/// Panics if every slot is already occupied; see [CALLBACK_SLOT_COUNT].
+fn claim_slot(ptr: *mut c_void) -> usize {
+    CALLBACK_SLOTS
+        .iter()
+        .position(|slot| {
+            slot.compare_exchange(
+                ptr::null_mut(),
+                ptr,
+                atomic::Ordering::AcqRel,
+                atomic::Ordering::Relaxed,
+            )
+            .is_ok()
+        })
+        .unwrap_or_else(|| panic!("exhausted all {CALLBACK_SLOT_COUNT} `Hook::connect` slots"))
+}
+
+/// Pick the monomorphized [hook_connect_trampoline] for `slot`, as a gsubr-ready function pointer.
+///
+/// `slot` is only known at runtime, but a distinct machine address is needed per slot (see
+/// [CALLBACK_SLOT_COUNT]), so this matches it against a literal arm per slot rather than taking it
+/// as a fourth generic parameter a caller could supply directly.
+fn trampoline_for<F, const ARITY: usize>(slot: usize) -> *mut c_void
+where
+    F: for<'a> HookConnectFn<'a, ARITY> + 'static,
+{
+    macro_rules! arms {
+        ($($n:literal),* $(,)?) => {
+            match slot {
+                $($n => hook_connect_trampoline::<F, ARITY, $n> as *mut c_void,)*
+                _ => unreachable!("slot index should already be bounds-checked by `claim_slot`"),
+            }
+        };
+    }
+    arms!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+}
+
+/// Trampoline registered by [`Hook::connect`] for slot `SLOT`; reads the [Mutex]-guarded closure
+/// back out of [CALLBACK_SLOTS] and calls it with the arguments Guile handed the hook.
+///
+/// Registered with no required/optional arguments and a single rest argument, rather than
+/// `ARITY` required arguments, since a C function's parameter list can't vary with a const
+/// generic; the rest list is destructured back into `ARITY` arguments here instead.
+extern "C" fn hook_connect_trampoline<F, const ARITY: usize, const SLOT: usize>(rest: SCM) -> SCM
+where
+    F: for<'a> HookConnectFn<'a, ARITY> + 'static,
+{
+    let guile = unsafe { Guile::new_unchecked_ref() };
+    let args: [Scm; ARITY] = unsafe { <List<Scm> as ReprScm>::from_ptr(rest) }
+        .into_iter()
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("hook should call a connected closure with exactly its arity's worth of arguments");
+
+    let ptr = CALLBACK_SLOTS[SLOT].load(atomic::Ordering::Acquire);
+    unsafe { &*ptr.cast::<Mutex<F>>() }
+        .lock()
+        .unwrap()
+        .call_scm(args, guile);
+
+    false.to_scm(guile).as_ptr()
+}
 unsafe impl<'gm, const ARITY: usize> ReprScm for Hook<'gm, ARITY> {}
 impl<'gm, const ARITY: usize> ToScm<'gm> for Hook<'gm, ARITY> {
     fn to_scm(self, _: &'gm Guile) -> Scm<'gm> {
@@ -186,8 +414,8 @@ impl<'gm, const ARITY: usize> ToScm<'gm> for Hook<'gm, ARITY> {
 }
 
 impl<'gm, const ARITY: usize> TryFromScm<'gm> for Hook<'gm, ARITY> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"hook")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"hook")
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -22,12 +22,17 @@ pub mod catch;
 pub mod collections;
 pub mod dynwind;
 mod eval;
+pub mod fluid;
 pub mod foreign_object;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+pub mod gc;
 mod guile_mode;
 pub mod hook;
 pub mod module;
 pub mod num;
 mod primitive;
+pub mod rand;
 #[doc(hidden)]
 pub mod reexports;
 pub mod reference;
@@ -36,11 +41,14 @@ pub mod string;
 pub mod subr;
 pub mod symbol;
 pub mod sys;
+pub mod type_name;
 mod utils;
+mod valgrind;
 
 use std::ptr::NonNull;
 
 pub use guile_mode::*;
+pub use primitive::tuple::Dotted;
 
 /// Token that proves the current thread is in guile mode.
 #[repr(transparent)]
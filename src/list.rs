@@ -21,7 +21,9 @@
 use {
     crate::{
         Api, Scm, ScmTy,
-        sys::{SCM_EOL, scm_car, scm_cdr, scm_cons, scm_length, scm_list_p, scm_null_p},
+        sys::{
+            SCM_EOL, scm_car, scm_cdr, scm_cons, scm_length, scm_list_p, scm_null_p, scm_reverse,
+        },
     },
     bstr::BStr,
     std::{
@@ -133,6 +135,36 @@ where
         self.pair = unsafe { Scm::from_ptr(lst) };
     }
 }
+impl<'id, T> FromIterator<T> for List<'id, T>
+where
+    T: ScmTy<'id>,
+{
+    /// Collect into a list, preserving the order of the iterator.
+    ///
+    /// This conses the items on in reverse like [Extend::extend], then reverses the
+    /// resulting list once, so `iter().collect::<List<_>>().into_iter()` round-trips to
+    /// the original sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::with_guile;
+    /// # #[cfg(not(miri))]
+    /// with_guile(|api| {
+    ///     let list = [1, 2, 3].into_iter().collect::<gargoyle::list::List<_>>();
+    ///     assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// }).unwrap();
+    /// ```
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut lst = unsafe { List::new() };
+        lst.extend(iter);
+        lst.pair = unsafe { Scm::from_ptr(scm_reverse(lst.pair.as_ptr())) };
+        lst
+    }
+}
 impl<'id, T> ScmTy<'id> for List<'id, T>
 where
     T: ScmTy<'id>,
@@ -151,11 +183,12 @@ where
     fn predicate(_: &Api, scm: &Scm) -> bool {
         unsafe { Scm::from_ptr(scm_list_p(scm.as_ptr())) }.is_true() && {
             // eagerly check all items for better error messages
-            IntoIter::<'id, Scm>(List {
+            List::<'id, Scm> {
                 // SAFETY: we don't do any writing
                 pair: unsafe { Scm::from_ptr(scm.as_ptr()).cast_lifetime() },
                 _marker: PhantomData,
-            })
+            }
+            .into_iter()
             .all(|i| i.is::<T>())
         }
     }
@@ -174,14 +207,105 @@ where
     type IntoIter = IntoIter<'id, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter(self)
+        let remaining = self.len();
+        IntoIter {
+            list: self,
+            remaining,
+        }
     }
 }
 
+/// A list that has only been checked to be a proper Guile list.
+///
+/// `<List<T> as ScmTy>::predicate` eagerly walks the whole pair chain checking every element
+/// against `T`, which pays an `O(n)` cost on top of the traversal the caller is about to do
+/// anyway. [Self::get_unchecked_shallow] checks nothing (the caller guarantees a proper list),
+/// deferring the per-element `T::predicate` check to [ShallowIntoIter::next], where a mismatch
+/// panics instead of being rejected up front. Prefer [List] when you want strict, early validation; prefer this when
+/// you intend to iterate a large list once.
 #[derive(Debug)]
-pub struct IntoIter<'id, T>(List<'id, T>)
+#[repr(transparent)]
+pub struct RawList<'id, T>(List<'id, T>)
 where
     T: ScmTy<'id>;
+impl<'id, T> RawList<'id, T>
+where
+    T: ScmTy<'id>,
+{
+    /// Wrap `scm` as a list without checking it or any element's type.
+    ///
+    /// # Safety
+    ///
+    /// `scm` must be a proper list (see `scm_list_p`). No checking of the type of the
+    /// elements is performed here; it happens lazily in [ShallowIntoIter::next].
+    pub unsafe fn get_unchecked_shallow(scm: Scm<'id>) -> Self {
+        Self(List {
+            pair: scm,
+            _marker: PhantomData,
+        })
+    }
+}
+impl<'id, T> IntoIterator for RawList<'id, T>
+where
+    T: ScmTy<'id>,
+{
+    type Item = T;
+    type IntoIter = ShallowIntoIter<'id, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ShallowIntoIter(self.0.into_iter())
+    }
+}
+
+/// An owning iterator over a [RawList] that type-checks each element lazily.
+///
+/// # Panics
+///
+/// [Iterator::next] panics if the next element does not satisfy `T::predicate`.
+#[derive(Debug)]
+pub struct ShallowIntoIter<'id, T>(IntoIter<'id, T>)
+where
+    T: ScmTy<'id>;
+impl<'id, T> ExactSizeIterator for ShallowIntoIter<'id, T> where T: ScmTy<'id> {}
+impl<'id, T> FusedIterator for ShallowIntoIter<'id, T> where T: ScmTy<'id> {}
+impl<'id, T> Iterator for ShallowIntoIter<'id, T>
+where
+    T: ScmTy<'id>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.list.is_empty() {
+            None
+        } else {
+            let api = unsafe { Api::new_unchecked() };
+            let car = unsafe { Scm::from_ptr(scm_car(self.0.list.pair.as_ptr())) };
+            assert!(
+                T::predicate(&api, &car),
+                "list element did not match expected type `{}`",
+                BStr::new(T::type_name().as_ref().to_bytes())
+            );
+            self.0.next()
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// An owning iterator over a [List].
+///
+/// The remaining length is computed once, when the iterator is created, and decremented on
+/// each call to [Iterator::next] so that [Iterator::size_hint] is `O(1)` instead of re-walking
+/// the remaining pair chain with `scm_length` on every call.
+#[derive(Debug)]
+pub struct IntoIter<'id, T>
+where
+    T: ScmTy<'id>,
+{
+    list: List<'id, T>,
+    remaining: usize,
+}
 impl<'id, T> IntoIter<'id, T>
 where
     T: ScmTy<'id>,
@@ -203,7 +327,7 @@ where
     /// }).unwrap();
     /// ```
     pub fn into_inner(self) -> List<'id, T> {
-        self.0
+        self.list
     }
 }
 impl<'id, T> ExactSizeIterator for IntoIter<'id, T> where T: ScmTy<'id> {}
@@ -215,19 +339,19 @@ where
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.0.is_empty() {
+        if self.list.is_empty() {
             None
         } else {
             let [car, cdr] = [scm_car, scm_cdr]
-                .map(|morphism| unsafe { Scm::from_ptr(morphism(self.0.pair.as_ptr())) });
-            self.0.pair = cdr;
+                .map(|morphism| unsafe { Scm::from_ptr(morphism(self.list.pair.as_ptr())) });
+            self.list.pair = cdr;
+            self.remaining -= 1;
 
             Some(unsafe { T::get_unchecked(&Api::new_unchecked(), car) })
         }
     }
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.0.len();
-        (len, Some(len))
+        (self.remaining, Some(self.remaining))
     }
 }
 
@@ -249,6 +373,28 @@ mod tests {
         assert_eq!(List::<'_, i32>::type_name().as_ref(), c"(list i32)");
     }
 
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn list_from_iter() {
+        with_guile(|_| {
+            let lst = [1, 2, 3].into_iter().collect::<List<_>>();
+            assert_eq!(lst.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn list_shallow_iter() {
+        with_guile(|api| {
+            let lst = unsafe {
+                RawList::<i32>::get_unchecked_shallow(api.make_list([1, 2, 3]).construct())
+            };
+            assert_eq!(lst.into_iter().collect::<Vec<_>>(), [3, 2, 1]);
+        })
+        .unwrap();
+    }
+
     #[cfg_attr(miri, ignore)]
     #[test]
     fn list_iter() {
@@ -15,21 +15,19 @@
 
 //! Manipulate modules and the environment.
 
-use {
-    crate::{
-        Guile,
-        collections::list::List,
-        reference::{Ref, RefMut, ReprScm},
-        scm::{Scm, ToScm, TryFromScm},
-        symbol::Symbol,
-        sys::{
-            SCM_MODULEP, scm_current_module, scm_defined_p, scm_maybe_resolve_module,
-            scm_module_define, scm_module_lookup, scm_module_public_interface, scm_resolve_module,
-            scm_variable_ref,
-        },
-        utils::{c_predicate, scm_predicate},
+use crate::{
+    Guile,
+    collections::list::List,
+    reference::{Ref, RefMut, ReprScm},
+    scm::{Scm, ToScm, TryFromScm},
+    symbol::Symbol,
+    sys::{
+        SCM_MODULEP, scm_current_module, scm_defined_p, scm_maybe_resolve_module,
+        scm_module_define, scm_module_lookup, scm_module_public_interface, scm_resolve_module,
+        scm_variable_ref,
     },
-    std::{borrow::Cow, ffi::CStr},
+    type_name::TypeName,
+    utils::{c_predicate, scm_predicate},
 };
 
 /// Module paths like `'(ice-9 sandbox)`
@@ -199,8 +197,8 @@ impl<'gm> Module<'gm> {
 }
 unsafe impl ReprScm for Module<'_> {}
 impl<'gm> TryFromScm<'gm> for Module<'gm> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"module")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"module")
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
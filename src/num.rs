@@ -21,14 +21,22 @@
 use {
     crate::{
         Guile,
+        reference::ReprScm,
         scm::{Scm, ToScm, TryFromScm},
+        string::String as GuileString,
         sys::{
-            scm_c_imag_part, scm_c_make_rectangular, scm_c_real_part, scm_from_double, scm_is_real,
-            scm_to_double,
+            SCM, scm_abs, scm_c_imag_part, scm_c_make_rectangular, scm_c_real_part,
+            scm_denominator, scm_divide, scm_expt, scm_from_double, scm_from_int64,
+            scm_from_uint64, scm_inexact_to_exact, scm_is_exact_integer, scm_is_real,
+            scm_is_signed_integer, scm_is_unsigned_integer, scm_num_eq_p, scm_number_to_string,
+            scm_numerator, scm_rationalize, scm_sqrt, scm_string_to_number, scm_to_double,
+            scm_to_int64, scm_to_uint64,
         },
-        utils::c_predicate,
+        utils::{c_predicate, scm_predicate},
     },
+    allocator_api2::vec::Vec,
     std::marker::PhantomData,
+    string::String as BufString,
 };
 
 /// # Safety
@@ -46,12 +54,35 @@ impl UInt<'_> for u32 {}
 impl UInt<'_> for u64 {}
 impl UInt<'_> for usize {}
 
+/// A homogeneous SRFI-4 numeric vector (`u8vector`, `f64vector`, ...), picking its backing Guile
+/// element tag from `T` the same way [Num]/[UInt] already pick `T`'s scalar conversion.
+///
+/// This is a thin alias over [`ByteVector`][crate::collections::byte_vector::ByteVector], which
+/// already bulk-copies its backing store in one allocation via
+/// [`from_slice`][crate::collections::byte_vector::ByteVector::from_slice] and
+/// [`as_slice`][crate::collections::byte_vector::ByteVector::as_slice] rather than converting
+/// elements one `Scm` at a time, so numeric code gets that fast path under the name it expects.
+pub type NumVector<'gm, T> = crate::collections::byte_vector::ByteVector<'gm, T>;
+
+/// A SRFI-4 `f32vector`; see [NumVector].
+pub type F32Vector<'gm> = NumVector<'gm, f32>;
+/// A SRFI-4 `f64vector`; see [NumVector].
+pub type F64Vector<'gm> = NumVector<'gm, f64>;
+/// A SRFI-4 `c32vector`, whose elements are
+/// [`Complex32`][crate::collections::byte_vector::Complex32] rather than a [Num] impl; see
+/// [NumVector].
+pub type C32Vector<'gm> = NumVector<'gm, crate::collections::byte_vector::Complex32>;
+/// A SRFI-4 `c64vector`, whose elements are
+/// [`Complex64`][crate::collections::byte_vector::Complex64] rather than a [Num] impl; see
+/// [NumVector].
+pub type C64Vector<'gm> = NumVector<'gm, crate::collections::byte_vector::Complex64>;
+
 macro_rules! impl_scm_traits_for_int {
     ($ty:ty, $ty_name:literal,
      $scm_is_int:path, $ptr:ty, $scm_to_int:path, $scm_from_int:path $(,)?) => {
         impl<'gm> $crate::scm::TryFromScm<'gm> for $ty {
-            fn type_name() -> ::std::borrow::Cow<'static, ::std::ffi::CStr> {
-                ::std::borrow::Cow::Borrowed(
+            fn type_name() -> $crate::type_name::TypeName {
+                $crate::type_name::TypeName::from_static(
                     const {
                         unsafe {
                             ::std::ffi::CStr::from_bytes_with_nul_unchecked(
@@ -166,8 +197,8 @@ impl_scm_traits_for_int!(
 );
 
 impl<'gm> TryFromScm<'gm> for f64 {
-    fn type_name() -> ::std::borrow::Cow<'static, ::std::ffi::CStr> {
-        const { ::std::borrow::Cow::Borrowed(c"f64") }
+    fn type_name() -> crate::type_name::TypeName {
+        crate::type_name::TypeName::from_static(c"f64")
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -206,6 +237,122 @@ macro_rules! impl_ops_for_num {
         }
     };
 }
+
+/// Returned by [`modexp`][Number::modexp] when given a zero modulus, which Guile's
+/// `scm_modulo_expt` would otherwise reject by raising a Scheme error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZeroModulusError;
+
+macro_rules! impl_modular_ops {
+    ($ident:ident) => {
+        impl<'gm> $ident<'gm> {
+            /// Compute `self` raised to `exponent`, modulo `modulus`, in `O(log exponent)` via
+            /// Guile's built-in square-and-multiply (`scm_modulo_expt`).
+            ///
+            /// Returns [`ZeroModulusError`] instead of triggering a Guile error when `modulus` is
+            /// zero.
+            pub fn modexp<E, M>(
+                self,
+                exponent: E,
+                modulus: M,
+            ) -> ::std::result::Result<$crate::num::Number<'gm>, ZeroModulusError>
+            where
+                E: for<'a> $crate::num::Num<'a>,
+                M: for<'a> $crate::num::Num<'a>,
+            {
+                // SAFETY: having a [Self] exist is proof of being in guile mode.
+                let guile = unsafe { $crate::Guile::new_unchecked() };
+                let modulus = modulus.to_scm(&guile).as_ptr();
+
+                if $crate::utils::scm_predicate(|| unsafe {
+                    $crate::sys::scm_num_eq_p(modulus, 0i32.to_scm(&guile).as_ptr())
+                }) {
+                    return ::std::result::Result::Err(ZeroModulusError);
+                }
+
+                ::std::result::Result::Ok($crate::num::Number {
+                    scm: unsafe {
+                        $crate::sys::scm_modulo_expt(
+                            self.to_scm(&guile).as_ptr(),
+                            exponent.to_scm(&guile).as_ptr(),
+                            modulus,
+                        )
+                    },
+                    _marker: ::std::marker::PhantomData,
+                })
+            }
+
+            /// `self` modulo `divisor`, following the sign of `divisor` (Guile's `scm_modulo`) —
+            /// like Python's `%`, unlike Rust's `%`.
+            pub fn modulo<D>(self, divisor: D) -> $crate::num::Number<'gm>
+            where
+                D: for<'a> $crate::num::Num<'a>,
+            {
+                let guile = unsafe { $crate::Guile::new_unchecked() };
+                $crate::num::Number {
+                    scm: unsafe {
+                        $crate::sys::scm_modulo(
+                            self.to_scm(&guile).as_ptr(),
+                            divisor.to_scm(&guile).as_ptr(),
+                        )
+                    },
+                    _marker: ::std::marker::PhantomData,
+                }
+            }
+
+            /// `self` remainder `divisor`, following the sign of `self` (Guile's
+            /// `scm_remainder`) — matches Rust's own `%` for integers.
+            pub fn remainder<D>(self, divisor: D) -> $crate::num::Number<'gm>
+            where
+                D: for<'a> $crate::num::Num<'a>,
+            {
+                let guile = unsafe { $crate::Guile::new_unchecked() };
+                $crate::num::Number {
+                    scm: unsafe {
+                        $crate::sys::scm_remainder(
+                            self.to_scm(&guile).as_ptr(),
+                            divisor.to_scm(&guile).as_ptr(),
+                        )
+                    },
+                    _marker: ::std::marker::PhantomData,
+                }
+            }
+
+            /// Divide `self` by `divisor`, returning `(quotient, remainder)` under Euclidean
+            /// division (Guile's `scm_euclidean_divide`), where the remainder is always
+            /// non-negative regardless of either operand's sign.
+            pub fn euclidean_div_mod<D>(
+                self,
+                divisor: D,
+            ) -> ($crate::num::Number<'gm>, $crate::num::Number<'gm>)
+            where
+                D: for<'a> $crate::num::Num<'a>,
+            {
+                let guile = unsafe { $crate::Guile::new_unchecked() };
+                let mut q = ::std::ptr::null_mut();
+                let mut r = ::std::ptr::null_mut();
+                unsafe {
+                    $crate::sys::scm_euclidean_divide(
+                        self.to_scm(&guile).as_ptr(),
+                        divisor.to_scm(&guile).as_ptr(),
+                        &mut q,
+                        &mut r,
+                    );
+                }
+                (
+                    $crate::num::Number {
+                        scm: q,
+                        _marker: ::std::marker::PhantomData,
+                    },
+                    $crate::num::Number {
+                        scm: r,
+                        _marker: ::std::marker::PhantomData,
+                    },
+                )
+            }
+        }
+    };
+}
 macro_rules! define_num {
     ($ident:ident, $type_name:literal, $predicate:path) => {
         // Numbers can be aliased since you cannot mutate them.
@@ -215,14 +362,16 @@ macro_rules! define_num {
             _marker: ::std::marker::PhantomData<&'guile_mode ()>,
         }
         impl<'gm> $crate::scm::TryFromScm<'gm> for $ident<'gm> {
-            fn type_name() -> ::std::borrow::Cow<'static, ::std::ffi::CStr> {
-                const {
-                    ::std::borrow::Cow::Borrowed(unsafe {
-                        ::std::ffi::CStr::from_bytes_with_nul_unchecked(
-                            concat!($type_name, "\0").as_bytes(),
-                        )
-                    })
-                }
+            fn type_name() -> $crate::type_name::TypeName {
+                $crate::type_name::TypeName::from_static(
+                    const {
+                        unsafe {
+                            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                                concat!($type_name, "\0").as_bytes(),
+                            )
+                        }
+                    },
+                )
             }
 
             fn predicate(scm: &$crate::scm::Scm<'gm>, _: &'gm $crate::Guile) -> bool {
@@ -249,6 +398,25 @@ macro_rules! define_num {
         impl_ops_for_num!($ident, Sub, sub, $crate::sys::scm_difference);
         impl_ops_for_num!($ident, Mul, mul, $crate::sys::scm_product);
         impl_ops_for_num!($ident, Div, div, $crate::sys::scm_divide);
+        impl_ops_for_num!($ident, Rem, rem, $crate::sys::scm_remainder);
+
+        impl<'gm> ::std::ops::Neg for $ident<'gm> {
+            type Output = $crate::num::Number<'gm>;
+
+            fn neg(self) -> Self::Output {
+                // SAFETY: having a [Self] exist is proof of being in guile mode.
+                let guile = unsafe { $crate::Guile::new_unchecked() };
+                $crate::num::Number {
+                    scm: unsafe {
+                        $crate::sys::scm_difference(
+                            self.to_scm(&guile).as_ptr(),
+                            $crate::sys::SCM_UNDEFINED,
+                        )
+                    },
+                    _marker: ::std::marker::PhantomData,
+                }
+            }
+        }
 
         impl<R> ::std::cmp::PartialEq<R> for $ident<'_>
         where
@@ -286,6 +454,45 @@ macro_rules! define_num {
 }
 
 define_num!(Number, "number", crate::sys::scm_is_number);
+impl_modular_ops!(Number);
+impl<'gm> Number<'gm> {
+    /// `self` raised to `exponent`, via Guile's `scm_expt`. Returns [`Number`] rather than
+    /// [`Real`] since an exact base can still round up into a [`Complex`] (e.g. `(-1).pow(0.5)`).
+    pub fn pow<E>(self, exponent: E) -> Number<'gm>
+    where
+        E: for<'a> Num<'a>,
+    {
+        let guile = unsafe { Guile::new_unchecked() };
+        Number {
+            scm: unsafe {
+                scm_expt(
+                    self.to_scm(&guile).as_ptr(),
+                    exponent.to_scm(&guile).as_ptr(),
+                )
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// The square root of `self`, via Guile's `scm_sqrt`. Returns [`Number`] rather than [`Real`]
+    /// since e.g. `sqrt(-1)` promotes to a [`Complex`].
+    pub fn sqrt(self) -> Number<'gm> {
+        let guile = unsafe { Guile::new_unchecked() };
+        Number {
+            scm: unsafe { scm_sqrt(self.to_scm(&guile).as_ptr()) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// The absolute value of `self`, via Guile's `scm_abs`.
+    pub fn abs(self) -> Number<'gm> {
+        let guile = unsafe { Guile::new_unchecked() };
+        Number {
+            scm: unsafe { scm_abs(self.to_scm(&guile).as_ptr()) },
+            _marker: PhantomData,
+        }
+    }
+}
 define_num!(Real, "real", crate::sys::scm_is_real);
 impl From<Real<'_>> for f64 {
     fn from(real: Real<'_>) -> f64 {
@@ -300,6 +507,277 @@ impl From<Rational<'_>> for f64 {
         unsafe { scm_to_double(rat.scm) }
     }
 }
+
+/// Returned by [`Rational::new`] when given a zero denominator, which `scm_divide` would
+/// otherwise reject by raising a Scheme error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZeroDenominatorError;
+
+impl<'gm> Rational<'gm> {
+    /// Construct the exact fraction `numerator / denominator` via `scm_divide`, erroring instead
+    /// of triggering a Guile error when `denominator` is zero.
+    pub fn new(
+        numerator: Integer<'gm>,
+        denominator: Integer<'gm>,
+        guile: &'gm Guile,
+    ) -> Result<Self, ZeroDenominatorError> {
+        let denominator = denominator.to_scm(guile).as_ptr();
+        if scm_predicate(|| unsafe { scm_num_eq_p(denominator, 0i32.to_scm(guile).as_ptr()) }) {
+            return Err(ZeroDenominatorError);
+        }
+
+        Ok(Self {
+            scm: unsafe { scm_divide(numerator.to_scm(guile).as_ptr(), denominator) },
+            _marker: PhantomData,
+        })
+    }
+
+    /// Construct the exact fraction `numerator / denominator` directly from `i64`s via
+    /// `scm_divide`, for callers who already have a ratio as a pair of machine integers (e.g. an
+    /// external `num_rational::Ratio<i64>`) rather than a pair of [`Integer`]s.
+    pub fn from_i64(
+        numerator: i64,
+        denominator: i64,
+        _: &'gm Guile,
+    ) -> Result<Self, ZeroDenominatorError> {
+        if denominator == 0 {
+            return Err(ZeroDenominatorError);
+        }
+
+        Ok(Self {
+            scm: unsafe { scm_divide(scm_from_int64(numerator), scm_from_int64(denominator)) },
+            _marker: PhantomData,
+        })
+    }
+
+    /// The fraction's reduced numerator.
+    pub fn numerator(&self) -> Integer<'gm> {
+        Integer {
+            scm: unsafe { scm_numerator(self.scm) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// The fraction's reduced denominator.
+    pub fn denominator(&self) -> Integer<'gm> {
+        Integer {
+            scm: unsafe { scm_denominator(self.scm) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// The simplest exact rational within `epsilon` of `value`, via Guile's `rationalize`
+    /// (`scm_rationalize`) applied to `value`'s exact equivalent (`scm_inexact_to_exact`), since
+    /// `rationalize` only returns an exact result when both of its arguments are already exact.
+    ///
+    /// This, together with [`Self::numerator`]/[`Self::denominator`] (each an [Integer], itself
+    /// convertible to `i64`/`i128` via [`TryFromScm`]), is this crate's bridge to an external
+    /// `num_rational::Ratio<i64>` for callers not using the `num-rational` feature; enable it for
+    /// a direct `TryFromScm`/`ToScm` impl on `Ratio<i64>` instead (see `num_rational_interop`
+    /// below).
+    pub fn from_f64(value: f64, epsilon: f64, guile: &'gm Guile) -> Self {
+        let exact = unsafe { scm_inexact_to_exact(value.to_scm(guile).as_ptr()) };
+        let epsilon = unsafe { scm_inexact_to_exact(epsilon.to_scm(guile).as_ptr()) };
+
+        Self {
+            scm: unsafe { scm_rationalize(exact, epsilon) },
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// [`num_rational::Ratio<i64>`] interop, enabled with the `num-rational` feature: exact
+/// arithmetic still happens in Guile (via [`Rational`]'s `Add`/`Sub`/`Mul`/`Div`, generated by
+/// [`define_num!`] the same way as every other [`Num`] type), while this lets callers move
+/// fractions to/from a pure-Rust ratio type at the boundary, built out of [`Rational::numerator`]
+/// and [`Rational::denominator`] rather than duplicating that plumbing.
+#[cfg(feature = "num-rational")]
+mod num_rational_interop {
+    use {
+        super::{Rational, ZeroDenominatorError},
+        crate::{
+            Guile,
+            scm::{Scm, ToScm, TryFromScm},
+            type_name::TypeName,
+        },
+        num_rational::Ratio,
+    };
+
+    impl<'gm> TryFromScm<'gm> for Ratio<i64> {
+        fn type_name() -> TypeName {
+            TypeName::from_static(c"rational")
+        }
+
+        fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
+            Rational::predicate(scm, guile)
+        }
+
+        unsafe fn from_scm_unchecked(scm: Scm<'gm>, guile: &'gm Guile) -> Self {
+            let rat = unsafe { Rational::from_scm_unchecked(scm, guile) };
+            let numerator =
+                unsafe { i64::from_scm_unchecked(rat.numerator().to_scm(guile), guile) };
+            let denominator =
+                unsafe { i64::from_scm_unchecked(rat.denominator().to_scm(guile), guile) };
+            Ratio::new_raw(numerator, denominator)
+        }
+    }
+    impl<'gm> ToScm<'gm> for Ratio<i64> {
+        fn to_scm(self, guile: &'gm Guile) -> Scm<'gm> {
+            // SAFETY: `Ratio` upholds a non-zero denominator as an invariant, so
+            // `Rational::from_i64` can't observe `ZeroDenominatorError` here.
+            Rational::from_i64(*self.numer(), *self.denom(), guile)
+                .map(|rat| rat.to_scm(guile))
+                .unwrap_or_else(|ZeroDenominatorError| unreachable!())
+        }
+    }
+}
+
+/// Guile's exact integers, unbounded in size (Guile promotes a fixnum to a bignum transparently
+/// once it overflows a machine word).
+define_num!(Integer, "integer", crate::sys::scm_is_exact_integer);
+impl_modular_ops!(Integer);
+
+/// Render `scm` (which must satisfy `scm_is_exact_integer`) as a signed hex string via Guile's own
+/// `number->string`, so magnitudes beyond what `scm_to_int64`/`scm_to_uint64` can hold still
+/// round-trip losslessly; `i128::from_str_radix`/`u128::from_str_radix`/`BigInt::parse_bytes` all
+/// accept the leading `-` Guile emits for negative values, so no sign-juggling is needed here.
+fn exact_integer_to_hex(scm: SCM, guile: &Guile) -> BufString<Vec<u8, crate::alloc::CAllocator>> {
+    let s = unsafe {
+        GuileString::from_scm_unchecked(
+            Scm::from_ptr_unchecked(scm_number_to_string(scm, 16u32.to_scm(guile).as_ptr())),
+            guile,
+        )
+    };
+    s.as_string()
+}
+/// Parse signed hex text (as produced by [exact_integer_to_hex]) back into a Guile exact integer
+/// via `string->number`.
+fn exact_integer_from_hex(hex: &str, guile: &Guile) -> SCM {
+    let s = GuileString::from_str(hex, guile);
+    unsafe { scm_string_to_number(s.as_ptr(), 16u32.to_scm(guile).as_ptr()) }
+}
+
+impl<'gm> TryFromScm<'gm> for i128 {
+    fn type_name() -> crate::type_name::TypeName {
+        crate::type_name::TypeName::from_static(c"s128")
+    }
+
+    fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
+        c_predicate(|| unsafe { scm_is_exact_integer(scm.as_ptr()) })
+            && (c_predicate(|| unsafe {
+                scm_is_signed_integer(scm.as_ptr(), i64::MIN as isize, i64::MAX as isize)
+            }) || i128::from_str_radix(&exact_integer_to_hex(scm.as_ptr(), guile), 16).is_ok())
+    }
+
+    unsafe fn from_scm_unchecked(scm: Scm<'gm>, guile: &'gm Guile) -> Self {
+        if c_predicate(|| unsafe {
+            scm_is_signed_integer(scm.as_ptr(), i64::MIN as isize, i64::MAX as isize)
+        }) {
+            i128::from(unsafe { scm_to_int64(scm.as_ptr()) })
+        } else {
+            i128::from_str_radix(&exact_integer_to_hex(scm.as_ptr(), guile), 16)
+                .expect("predicate guarantees this is representable")
+        }
+    }
+}
+impl<'gm> ToScm<'gm> for i128 {
+    fn to_scm(self, guile: &'gm Guile) -> Scm<'gm> {
+        match i64::try_from(self) {
+            Ok(v) => Scm::from_ptr(unsafe { scm_from_int64(v) }, guile),
+            Err(_) => {
+                let hex = if self < 0 {
+                    std::format!("-{:x}", self.unsigned_abs())
+                } else {
+                    std::format!("{self:x}")
+                };
+                Scm::from_ptr(exact_integer_from_hex(&hex, guile), guile)
+            }
+        }
+    }
+}
+unsafe impl Num<'_> for i128 {}
+
+impl<'gm> TryFromScm<'gm> for u128 {
+    fn type_name() -> crate::type_name::TypeName {
+        crate::type_name::TypeName::from_static(c"u128")
+    }
+
+    fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
+        c_predicate(|| unsafe { scm_is_exact_integer(scm.as_ptr()) })
+            && (c_predicate(|| unsafe {
+                scm_is_unsigned_integer(scm.as_ptr(), 0, u64::MAX as usize)
+            }) || u128::from_str_radix(&exact_integer_to_hex(scm.as_ptr(), guile), 16).is_ok())
+    }
+
+    unsafe fn from_scm_unchecked(scm: Scm<'gm>, guile: &'gm Guile) -> Self {
+        if c_predicate(|| unsafe { scm_is_unsigned_integer(scm.as_ptr(), 0, u64::MAX as usize) }) {
+            u128::from(unsafe { scm_to_uint64(scm.as_ptr()) })
+        } else {
+            u128::from_str_radix(&exact_integer_to_hex(scm.as_ptr(), guile), 16)
+                .expect("predicate guarantees this is representable")
+        }
+    }
+}
+impl<'gm> ToScm<'gm> for u128 {
+    fn to_scm(self, guile: &'gm Guile) -> Scm<'gm> {
+        match u64::try_from(self) {
+            Ok(v) => Scm::from_ptr(unsafe { scm_from_uint64(v) }, guile),
+            Err(_) => Scm::from_ptr(
+                exact_integer_from_hex(&std::format!("{self:x}"), guile),
+                guile,
+            ),
+        }
+    }
+}
+unsafe impl Num<'_> for u128 {}
+
+/// [`num_bigint::BigInt`] interop, enabled with the `bigint` feature: arithmetic still happens in
+/// Guile (via [`Integer`]'s `Add`/`Sub`/`Mul`/`Div`, which never lose precision since Guile's own
+/// bignums are exact), while this lets callers move values to/from a pure-Rust bignum type at the
+/// boundary.
+#[cfg(feature = "bigint")]
+mod bigint {
+    use {
+        super::{exact_integer_from_hex, exact_integer_to_hex},
+        crate::{
+            Guile,
+            num::Num,
+            scm::{Scm, ToScm, TryFromScm},
+            sys::scm_is_exact_integer,
+            type_name::TypeName,
+            utils::c_predicate,
+        },
+        num_bigint::BigInt,
+    };
+
+    impl<'gm> TryFromScm<'gm> for BigInt {
+        fn type_name() -> TypeName {
+            TypeName::from_static(c"bigint")
+        }
+
+        fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
+            c_predicate(|| unsafe { scm_is_exact_integer(scm.as_ptr()) })
+        }
+
+        unsafe fn from_scm_unchecked(scm: Scm<'gm>, guile: &'gm Guile) -> Self {
+            let hex = exact_integer_to_hex(scm.as_ptr(), guile);
+            BigInt::parse_bytes(hex.as_bytes(), 16).expect(
+                "guile's number->string always produces text string->number radix 16 can parse",
+            )
+        }
+    }
+    impl<'gm> ToScm<'gm> for BigInt {
+        fn to_scm(self, guile: &'gm Guile) -> Scm<'gm> {
+            Scm::from_ptr(exact_integer_from_hex(&self.to_str_radix(16), guile), guile)
+        }
+    }
+    unsafe impl Num<'_> for BigInt {}
+}
+
+/// Guile's complex numbers. [`Self::real_part`]/[`Self::imag_part`] are this crate's bridge to
+/// an external `num_complex::Complex<f64>` for callers not using the `num-complex` feature;
+/// enable it for a direct `TryFromScm`/`ToScm` impl on `Complex<f64>` instead (see
+/// `num_complex_interop` below).
 define_num!(Complex, "complex", crate::sys::scm_is_complex);
 impl Complex<'_> {
     pub fn real_part(&self) -> f64 {
@@ -318,6 +796,41 @@ impl<'gm> Complex<'gm> {
     }
 }
 
+/// [`num_complex::Complex<f64>`] interop, enabled with the `num-complex` feature, built out of
+/// [`Complex::real_part`]/[`Complex::imag_part`] rather than duplicating that plumbing.
+#[cfg(feature = "num-complex")]
+mod num_complex_interop {
+    use {
+        super::Complex,
+        crate::{
+            Guile,
+            scm::{Scm, ToScm, TryFromScm},
+            type_name::TypeName,
+        },
+        num_complex::Complex as ExternalComplex,
+    };
+
+    impl<'gm> TryFromScm<'gm> for ExternalComplex<f64> {
+        fn type_name() -> TypeName {
+            TypeName::from_static(c"complex")
+        }
+
+        fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
+            Complex::predicate(scm, guile)
+        }
+
+        unsafe fn from_scm_unchecked(scm: Scm<'gm>, guile: &'gm Guile) -> Self {
+            let complex = unsafe { Complex::from_scm_unchecked(scm, guile) };
+            ExternalComplex::new(complex.real_part(), complex.imag_part())
+        }
+    }
+    impl<'gm> ToScm<'gm> for ExternalComplex<f64> {
+        fn to_scm(self, guile: &'gm Guile) -> Scm<'gm> {
+            Complex::new(self.re, self.im, guile).to_scm(guile)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {super::*, crate::with_guile};
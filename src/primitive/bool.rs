@@ -18,20 +18,18 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE.
 
-use {
-    crate::{
-        Guile,
-        reference::ReprScm,
-        scm::{Scm, ToScm, TryFromScm},
-        sys::{SCM_BOOL_F, SCM_BOOL_T, scm_is_bool},
-        utils::c_predicate,
-    },
-    std::{borrow::Cow, ffi::CStr},
+use crate::{
+    Guile,
+    reference::ReprScm,
+    scm::{Scm, ToScm, TryFromScm},
+    sys::{SCM_BOOL_F, SCM_BOOL_T, scm_is_bool},
+    type_name::TypeName,
+    utils::c_predicate,
 };
 
 impl<'gm> TryFromScm<'gm> for bool {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"bool")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"bool")
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -13,15 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use {
-    crate::{
-        Guile,
-        reference::ReprScm,
-        scm::{Scm, ToScm, TryFromScm},
-        sys::{scm_char_p, scm_char_to_integer, scm_integer_to_char},
-        utils::scm_predicate,
-    },
-    std::{borrow::Cow, ffi::CStr},
+use crate::{
+    Guile,
+    reference::ReprScm,
+    scm::{ConversionError, Scm, ToScm, TryFromScm},
+    sys::{scm_char_p, scm_char_to_integer, scm_integer_to_char},
+    type_name::TypeName,
+    utils::scm_predicate,
 };
 
 impl<'gm> ToScm<'gm> for char {
@@ -30,22 +28,37 @@ impl<'gm> ToScm<'gm> for char {
         Scm::from_ptr(unsafe { scm_integer_to_char(scm) }, guile)
     }
 }
+
+/// Guile chars hold a full 32-bit codepoint, some of which (surrogates, values past
+/// `char::MAX`) aren't valid Rust [char]s; returns [None] for those.
+fn guile_char_to_char<'gm>(scm: &Scm<'gm>, guile: &'gm Guile) -> Option<char> {
+    u32::try_from_scm(
+        Scm::from_ptr(unsafe { scm_char_to_integer(scm.as_ptr()) }, guile),
+        guile,
+    )
+    .ok()
+    .and_then(|ch| char::try_from(ch).ok())
+}
+
 impl<'gm> TryFromScm<'gm> for char {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"char")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"char")
     }
 
-    fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
+    fn predicate(scm: &Scm<'gm>, guile: &'gm Guile) -> bool {
         scm_predicate(unsafe { scm_char_p(scm.as_ptr()) })
+            && guile_char_to_char(scm, guile).is_some()
     }
+
+    fn try_from_scm_checked(scm: Scm<'gm>, guile: &'gm Guile) -> Result<Self, ConversionError> {
+        if !scm_predicate(unsafe { scm_char_p(scm.as_ptr()) }) {
+            return Err(ConversionError::WrongType);
+        }
+        guile_char_to_char(&scm, guile).ok_or(ConversionError::OutOfRange)
+    }
+
     unsafe fn from_scm_unchecked(scm: Scm<'gm>, guile: &'gm Guile) -> Self {
-        u32::try_from_scm(
-            Scm::from_ptr(unsafe { scm_char_to_integer(scm.as_ptr()) }, guile),
-            guile,
-        )
-        .ok()
-        .and_then(|ch| char::try_from(ch).ok())
-        .unwrap()
+        guile_char_to_char(&scm, guile).unwrap()
     }
 }
 
@@ -63,4 +76,22 @@ mod tests {
         })
         .unwrap();
     }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn char_out_of_range() {
+        with_guile(|guile| {
+            // a UTF-16 surrogate codepoint: a valid Guile char, but not a valid Rust `char`
+            let scm = Scm::from_ptr(
+                unsafe { scm_integer_to_char(0xd800u32.to_scm(guile).as_ptr()) },
+                guile,
+            );
+            assert!(!char::predicate(&scm, guile));
+            assert_eq!(
+                char::try_from_scm_checked(scm, guile),
+                Err(ConversionError::OutOfRange),
+            );
+        })
+        .unwrap();
+    }
 }
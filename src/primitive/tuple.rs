@@ -59,7 +59,7 @@ macro_rules! impl_tuple {
             }
         }
         impl<'gm> $crate::scm::TryFromScm<'gm> for () {
-            fn type_name() -> ::std::borrow::Cow<'static, ::std::ffi::CStr> {
+            fn type_name() -> $crate::type_name::TypeName {
                 $crate::collections::list::Null::type_name()
             }
 
@@ -93,18 +93,16 @@ macro_rules! impl_tuple {
             $car: $crate::scm::TryFromScm<'gm>,
             $($($cdr: $crate::scm::TryFromScm<'gm>),+)?
         {
-            fn type_name() -> ::std::borrow::Cow<'static, ::std::ffi::CStr> {
-                #[allow(unused_macros)]
-                macro_rules! add_string {
-                    ($fst:literal $drop:tt) => { $fst };
-                }
-                ::std::ffi::CString::new(format!(
-                    concat!("'(", "{}", $($(add_string!(" " $cdr), add_string!("{}" $cdr),)+)? ")"),
-                    $crate::reexports::bstr::BStr::new(<$car as $crate::scm::TryFromScm>::type_name().as_ref().to_bytes()),
-                    $($($crate::reexports::bstr::BStr::new(<$cdr as $crate::scm::TryFromScm>::type_name().as_ref().to_bytes()),)+)?
-                ))
-                    .map(::std::borrow::Cow::Owned)
-                    .unwrap_or(::std::borrow::Cow::Borrowed(c"list"))
+            fn type_name() -> $crate::type_name::TypeName {
+                let mut builder = $crate::type_name::TypeNameBuilder::new();
+                builder.push(b"'(");
+                builder.push(<$car as $crate::scm::TryFromScm>::type_name().to_bytes());
+                $($(
+                    builder.push(b" ");
+                    builder.push(<$cdr as $crate::scm::TryFromScm>::type_name().to_bytes());
+                )+)?
+                builder.push(b")");
+                builder.finish()
             }
 
             fn predicate(scm: &$crate::scm::Scm<'gm>, guile: &'gm $crate::Guile) -> bool {
@@ -127,3 +125,114 @@ macro_rules! impl_tuple {
     };
 }
 impl_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// A tuple type ending in `$last` instead of [`Null`][crate::collections::list::Null], i.e. a
+/// right-nested cons chain with no proper-list terminator.
+macro_rules! dotted_cons_ty {
+    ($last:ty) => {
+        $last
+    };
+    ($car:ty, $($cdr:ty),+) => {
+        $crate::collections::pair::Pair<$car, dotted_cons_ty!($($cdr),+)>
+    };
+}
+/// Build a [`dotted_cons_ty!`] value by folding [`Pair::new`][crate::collections::pair::Pair::new]
+/// from the right, leaving `$last` as the final cdr rather than consing it onto [`Null`].
+macro_rules! dotted_cons {
+    ($guile:expr, $last:expr) => {
+        $last
+    };
+    ($guile:expr, $car:expr, $($cdr:expr),+) => {
+        $crate::collections::pair::Pair::new($car, dotted_cons!($guile, $($cdr),+), $guile)
+    };
+}
+/// Push the `" {car}"` / `" . {last}"` pieces of a dotted tuple's [`type_name`][crate::scm::TryFromScm::type_name] onto `$builder`, one type at a time.
+macro_rules! dotted_type_name_parts {
+    ($builder:expr; $last:ident) => {
+        $builder.push(b" . ");
+        $builder.push(<$last as $crate::scm::TryFromScm>::type_name().to_bytes());
+    };
+    ($builder:expr; $car:ident, $($cdr:ident),+) => {
+        $builder.push(b" ");
+        $builder.push(<$car as $crate::scm::TryFromScm>::type_name().to_bytes());
+        dotted_type_name_parts!($builder; $($cdr),+);
+    };
+}
+/// Flatten a [`dotted_cons_ty!`] value back into a flat tuple, peeling one [`Pair::to_tuple`][crate::collections::pair::Pair::to_tuple] off the front at a time.
+macro_rules! dotted_flatten {
+    ($chain:expr; $last:ident) => {
+        $chain
+    };
+    ($chain:expr; $car:ident, $($cdr:ident),+) => {{
+        #[expect(non_snake_case)]
+        let ($car, rest) = $crate::collections::pair::Pair::to_tuple($chain);
+        #[expect(non_snake_case)]
+        let ($($cdr),+) = dotted_flatten!(rest; $($cdr),+);
+        ($car, $($cdr),+)
+    }};
+}
+
+/// Wrapper requesting a *dotted* (improper) cons-chain encoding of a tuple, rather than the proper
+/// list [`impl_tuple!`] builds for the bare tuple types: `Dotted((1, 2, 3))` round-trips as the
+/// improper list `(1 2 . 3)`, with the last element occupying the final cdr, instead of `(1 2 3)`.
+///
+/// This is for Scheme forms that genuinely use dotted tails — `lambda` argument lists, many
+/// record accessors — rather than being forced through a proper-list conversion.
+///
+/// # Examples
+/// ```
+/// # use garguile::{Dotted, string::String, with_guile};
+/// # #[cfg(not(miri))]
+/// with_guile(|guile| {
+///     assert_eq!(
+///         unsafe { guile.eval::<Dotted<(i32, i32, i32)>>(&String::from_str("'(1 2 . 3)", guile)) },
+///         Ok(Dotted((1, 2, 3))),
+///     );
+/// }).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dotted<T>(pub T);
+
+macro_rules! impl_dotted_tuple {
+    ($car:ident) => {};
+    ($car:ident, $($cdr:ident),+) => {
+        impl<'gm, $car, $($cdr),+> $crate::scm::ToScm<'gm> for Dotted<($car, $($cdr),+)>
+        where
+            $car: $crate::scm::ToScm<'gm>,
+            $($cdr: $crate::scm::ToScm<'gm>),+
+        {
+            fn to_scm(self, guile: &'gm $crate::Guile) -> Scm<'gm> {
+                #[expect(non_snake_case)]
+                let Dotted(($car, $($cdr),+)) = self;
+                dotted_cons!(guile, $car, $($cdr),+).to_scm(guile)
+            }
+        }
+        impl<'gm, $car, $($cdr),+> $crate::scm::TryFromScm<'gm> for Dotted<($car, $($cdr),+)>
+        where
+            $car: $crate::scm::TryFromScm<'gm>,
+            $($cdr: $crate::scm::TryFromScm<'gm>),+
+        {
+            fn type_name() -> $crate::type_name::TypeName {
+                let mut builder = $crate::type_name::TypeNameBuilder::new();
+                builder.push(b"'(");
+                builder.push(<$car as $crate::scm::TryFromScm>::type_name().to_bytes());
+                dotted_type_name_parts!(builder; $($cdr),+);
+                builder.push(b")");
+                builder.finish()
+            }
+
+            fn predicate(scm: &$crate::scm::Scm<'gm>, guile: &'gm $crate::Guile) -> bool {
+                <dotted_cons_ty!($car, $($cdr),+)>::predicate(scm, guile)
+            }
+
+            #[expect(non_snake_case)]
+            unsafe fn from_scm_unchecked(scm: $crate::scm::Scm<'gm>, guile: &'gm $crate::Guile) -> Self {
+                let chain = unsafe { <dotted_cons_ty!($car, $($cdr),+)>::from_scm_unchecked(scm, guile) };
+                Dotted(dotted_flatten!(chain; $car, $($cdr),+))
+            }
+        }
+
+        impl_dotted_tuple!($($cdr),+);
+    };
+}
+impl_dotted_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
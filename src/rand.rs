@@ -1,22 +1,25 @@
-// gargoyle - guile bindings for rust
+// garguile - guile bindings for rust
 // Copyright (C) 2025  Andrew Chi
 
-// Permission is hereby granted, free of charge, to any person obtaining a copy
-// of this software and associated documentation files (the "Software"), to deal
-// in the Software without restriction, including without limitation the rights
-// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
-// copies of the Software, and to permit persons to whom the Software is
-// furnished to do so, subject to the following conditions:
-// The above copyright notice and this permission notice shall be included in
-// all copies or substantial portions of the Software.
-
-// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
-// THE SOFTWARE.
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Guile's `scm_random` state, bridged to Rust.
+//!
+//! [GuileRng] exposes `next_u32`/`next_u64`/`fill_bytes`/`seed_from_u64`/`from_seed`, matching
+//! `rand_core::RngCore`/`SeedableRng`'s method shapes exactly, so a downstream crate depending on
+//! both this crate and `rand_core` can implement those traits for [GuileRng] as pure delegation.
+//! This crate has no `Cargo.toml` in which to add `rand_core` as a dependency itself, so the trait
+//! impls aren't provided here, only the methods they'd delegate to.
 
 use {
     crate::{
@@ -28,24 +31,104 @@ use {
     std::ops::RangeTo,
 };
 
+/// An owned Guile random state, independent of any particular upper bound.
+pub struct GuileRng<'gm> {
+    random_state: Scm<'gm>,
+}
+impl<'gm> GuileRng<'gm> {
+    /// Seed a fresh state from an arbitrary Guile number via `scm_seed_to_random_state`.
+    pub fn from_scm_seed<S>(seed: S, guile: &'gm Guile) -> Self
+    where
+        S: Num<'gm>,
+    {
+        Self {
+            random_state: Scm::from_ptr(
+                unsafe { scm_seed_to_random_state(seed.to_scm(guile).as_ptr()) },
+                guile,
+            ),
+        }
+    }
+
+    /// See `rand_core::SeedableRng::seed_from_u64`.
+    pub fn seed_from_u64(seed: u64, guile: &'gm Guile) -> Self {
+        Self::from_scm_seed(seed, guile)
+    }
+
+    /// See `rand_core::SeedableRng::from_seed`; the 32 bytes are folded down to a single `u64`
+    /// before seeding, since `scm_seed_to_random_state` takes one Guile number, not a byte array.
+    pub fn from_seed(seed: [u8; 32], guile: &'gm Guile) -> Self {
+        let seed = seed.iter().fold(0u64, |acc, &byte| {
+            acc.wrapping_mul(31).wrapping_add(byte.into())
+        });
+        Self::seed_from_u64(seed, guile)
+    }
+
+    /// A uniform value in `[0, bound)`, the primitive every other draw on this type is built
+    /// from.
+    fn below<T>(&mut self, bound: T, guile: &'gm Guile) -> T
+    where
+        T: UInt<'gm>,
+    {
+        T::try_from_scm(
+            Scm::from_ptr(
+                unsafe { scm_random(bound.to_scm(guile).as_ptr(), self.random_state.as_ptr()) },
+                guile,
+            ),
+            guile,
+        )
+        .expect("`scm_random` should stay within the requested bound")
+    }
+
+    /// See `rand_core::RngCore::next_u32`.
+    pub fn next_u32(&mut self) -> u32 {
+        let guile = unsafe { Guile::new_unchecked() };
+        self.below(4_294_967_296u64, &guile)
+            .try_into()
+            .expect("a draw below 2^32 fits in a u32")
+    }
+
+    /// See `rand_core::RngCore::next_u64`; composed from two `next_u32` draws rather than a
+    /// single `scm_random` call, since [UInt] (unlike [Num]) isn't implemented for `u128`.
+    pub fn next_u64(&mut self) -> u64 {
+        let hi = u64::from(self.next_u32());
+        let lo = u64::from(self.next_u32());
+        (hi << 32) | lo
+    }
+
+    /// See `rand_core::RngCore::fill_bytes`.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_ne_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+impl Clone for GuileRng<'_> {
+    /// Reproduce the same future stream via `scm_copy_random_state`.
+    fn clone(&self) -> Self {
+        Self {
+            random_state: unsafe {
+                Scm::from_ptr_unchecked(scm_copy_random_state(self.random_state.as_ptr()))
+            },
+        }
+    }
+}
+
+/// An iterator over values drawn uniformly from `..end`, built on top of [GuileRng].
 pub struct Generator<'gm, T>
 where
     T: UInt<'gm>,
 {
-    random_state: Scm<'gm>,
+    rng: GuileRng<'gm>,
     end: RangeTo<T>,
 }
-
-// pub struct Generator<'guile_mode, T>(Scm<'guile_mode>);
 impl<'gm, T> Clone for Generator<'gm, T>
 where
     T: UInt<'gm>,
 {
     fn clone(&self) -> Self {
         Self {
-            random_state: unsafe {
-                Scm::from_ptr_unchecked(scm_copy_random_state(self.random_state.as_ptr()))
-            },
+            rng: self.rng.clone(),
             end: self.end.clone(),
         }
     }
@@ -59,10 +142,7 @@ where
         S: Num<'gm>,
     {
         Self {
-            random_state: Scm::from_ptr(
-                unsafe { scm_seed_to_random_state(seed.to_scm(guile).as_ptr()) },
-                guile,
-            ),
+            rng: GuileRng::from_scm_seed(seed, guile),
             end,
         }
     }
@@ -75,19 +155,7 @@ where
 
     fn next(&mut self) -> Option<T> {
         let guile = unsafe { Guile::new_unchecked() };
-        T::try_from_scm(
-            Scm::from_ptr(
-                unsafe {
-                    scm_random(
-                        self.end.end.to_scm(&guile).as_ptr(),
-                        self.random_state.as_ptr(),
-                    )
-                },
-                &guile,
-            ),
-            &guile,
-        )
-        .ok()
+        Some(self.rng.below(self.end.end, &guile))
     }
 }
 
@@ -114,4 +182,17 @@ mod tests {
             })
         });
     }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn guile_rng_clone_reproduces_stream() {
+        with_guile(|guile| {
+            let mut a = GuileRng::seed_from_u64(0, guile);
+            let mut b = a.clone();
+
+            (0..=1_000).for_each(|_| {
+                assert_eq!(a.next_u64(), b.next_u64());
+            });
+        });
+    }
 }
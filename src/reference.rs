@@ -18,7 +18,7 @@
 use {
     crate::{
         Guile,
-        scm::{Scm, TryFromScm},
+        scm::{ConversionError, Scm, ToScm, TryFromScm},
         sys::SCM,
     },
     std::{
@@ -101,6 +101,10 @@ impl<'gm, T> Ref<'_, 'gm, T> {
 
     /// Copy the data from the reference.
     ///
+    /// # Panics
+    ///
+    /// Panics if the conversion fails; see [Self::try_copied] for a non-panicking variant.
+    ///
     /// # Examples
     ///
     /// ```
@@ -111,12 +115,34 @@ impl<'gm, T> Ref<'_, 'gm, T> {
     /// }).unwrap();
     /// ```
     pub fn copied(self) -> T
+    where
+        T: Copy + TryFromScm<'gm>,
+    {
+        self.try_copied().unwrap()
+    }
+
+    /// Copy the data from the reference, reporting a failed conversion as a [ConversionError]
+    /// instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::pair::Pair, reference::Ref, scm::{ConversionError, ToScm}, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(Pair::new(0, 1, guile).as_car().try_copied(), Ok(0));
+    ///
+    ///     let mismatched = unsafe { Ref::<i32>::new_unchecked(true.to_scm(guile).as_ptr()) };
+    ///     assert_eq!(mismatched.try_copied(), Err(ConversionError::WrongType));
+    /// }).unwrap();
+    /// ```
+    pub fn try_copied(self) -> Result<T, ConversionError>
     where
         T: Copy + TryFromScm<'gm>,
     {
         let guile = unsafe { Guile::new_unchecked_ref() };
         let ptr = Scm::from_ptr(self.ptr, guile);
-        T::try_from_scm(ptr, guile).unwrap()
+        T::try_from_scm_checked(ptr, guile)
     }
 }
 impl<'a, 'gm, T> Ref<'a, 'gm, T> {
@@ -152,16 +178,47 @@ where
     }
 }
 
+/// Writes `value` into slot `index` of the collection rooted at `owner`, e.g. `scm_set_car_x` for
+/// a pair or `scm_c_vector_set_x` for a vector; `index` is ignored by writers for slots that
+/// aren't indexed (pairs, association entries).
+///
+/// # Safety
+///
+/// `owner` must still be the live collection the registering [RefMut] was built from, and `index`
+/// must still be in bounds for it.
+pub(crate) type Writer = unsafe fn(owner: SCM, index: usize, value: SCM);
+
 /// Mutable reference created with a [Scm].
-#[repr(transparent)]
-pub struct RefMut<'a, 'gm, T>(Ref<'a, 'gm, T>);
+pub struct RefMut<'a, 'gm, T> {
+    inner: Ref<'a, 'gm, T>,
+    /// The write-back location, if this reference has one; `None` for references that only hand
+    /// out a snapshot, e.g. [Module::define][crate::module::Module::define].
+    slot: Option<(Writer, SCM, usize)>,
+}
 impl<'gm, T> RefMut<'_, 'gm, T> {
     /// # Safety
     ///
     /// See [Ref::new_unchecked].
     /// `ptr` must also not be aliased.
     pub unsafe fn new_unchecked(ptr: SCM) -> Self {
-        Self(unsafe { Ref::new_unchecked(ptr) })
+        Self {
+            inner: unsafe { Ref::new_unchecked(ptr) },
+            slot: None,
+        }
+    }
+
+    /// Like [Self::new_unchecked], but also registers a write-back location so [Self::set] and
+    /// [Self::replace] can store into the collection this reference was read from.
+    ///
+    /// # Safety
+    ///
+    /// See [Self::new_unchecked]. Additionally, calling `write(owner, index, value)` must be a
+    /// sound way to store `value` into this slot for as long as the returned [RefMut] exists.
+    pub(crate) unsafe fn with_writer(ptr: SCM, write: Writer, owner: SCM, index: usize) -> Self {
+        Self {
+            inner: unsafe { Ref::new_unchecked(ptr) },
+            slot: Some((write, owner, index)),
+        }
     }
 
     /// See [Ref::copied]
@@ -169,7 +226,75 @@ impl<'gm, T> RefMut<'_, 'gm, T> {
     where
         T: Copy + TryFromScm<'gm>,
     {
-        self.0.copied()
+        self.inner.copied()
+    }
+
+    /// See [Ref::try_copied]
+    pub fn try_copied(self) -> Result<T, ConversionError>
+    where
+        T: Copy + TryFromScm<'gm>,
+    {
+        self.inner.try_copied()
+    }
+
+    /// Store `value` into the slot this reference was read from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this reference has no backing slot to write into, e.g. one returned by
+    /// [Module::define][crate::module::Module::define].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::pair::Pair, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut pair = Pair::new(1, 2, guile);
+    ///     pair.as_mut_car().set(3);
+    ///     assert_eq!(pair.as_car().copied(), 3);
+    /// }).unwrap();
+    /// ```
+    pub fn set<V>(&mut self, value: V)
+    where
+        V: ToScm<'gm>,
+    {
+        let (write, owner, index) = self
+            .slot
+            .expect("reference has no backing slot to write into");
+        let guile = unsafe { Guile::new_unchecked_ref() };
+        let ptr = value.to_scm(guile).as_ptr();
+        unsafe {
+            write(owner, index, ptr);
+        }
+        self.inner = unsafe { Ref::new_unchecked(ptr) };
+    }
+
+    /// Store `value` into the slot this reference was read from, returning the previous value.
+    ///
+    /// # Panics
+    ///
+    /// See [Self::set].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{collections::pair::Pair, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let mut pair = Pair::new(1, 2, guile);
+    ///     assert_eq!(pair.as_mut_car().replace(3), 1);
+    ///     assert_eq!(pair.as_car().copied(), 3);
+    /// }).unwrap();
+    /// ```
+    pub fn replace<V>(&mut self, value: V) -> T
+    where
+        T: Copy + TryFromScm<'gm>,
+        V: ToScm<'gm>,
+    {
+        let prev = self.inner.copied();
+        self.set(value);
+        prev
     }
 }
 impl<T> Deref for RefMut<'_, '_, T>
@@ -179,7 +304,7 @@ where
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        self.0.deref()
+        self.inner.deref()
     }
 }
 impl<T> DerefMut for RefMut<'_, '_, T>
@@ -187,7 +312,7 @@ where
     T: ReprScm,
 {
     fn deref_mut(&mut self) -> &mut T {
-        unsafe { mem::transmute(self) }
+        unsafe { mem::transmute(&mut self.inner) }
     }
 }
 
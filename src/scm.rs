@@ -28,15 +28,27 @@ use {
             SCM, SCM_UNBNDP, scm_equal_p, scm_is_false, scm_is_true, scm_null_p,
             scm_wrong_type_arg_msg,
         },
+        type_name::TypeName,
         utils::{c_predicate, scm_predicate},
     },
-    std::{borrow::Cow, ffi::CStr, marker::PhantomData},
+    std::{ffi::CStr, marker::PhantomData, mem},
 };
 
+/// Why a [TryFromScm] conversion failed without panicking, returned by
+/// [TryFromScm::try_from_scm_checked].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The [Scm] didn't satisfy [TryFromScm::predicate] for the target type.
+    WrongType,
+    /// The [Scm] satisfied the predicate, but its value falls outside the range the target Rust
+    /// type can represent.
+    OutOfRange,
+}
+
 /// Trait for types that can be converted from a [Scm] object.
 pub trait TryFromScm<'gm> {
     /// The name of the type
-    fn type_name() -> Cow<'static, CStr>;
+    fn type_name() -> TypeName;
 
     /// Whether or not the object is this type
     fn predicate(_: &Scm<'gm>, _: &'gm Guile) -> bool;
@@ -71,6 +83,24 @@ pub trait TryFromScm<'gm> {
         })
     }
 
+    /// Attempt the conversion, reporting failure as a [ConversionError] instead of panicking.
+    ///
+    /// The default just checks [predicate][Self::predicate] and delegates to
+    /// [from_scm_unchecked][Self::from_scm_unchecked], so it can only ever fail with
+    /// [ConversionError::WrongType]. Override it when a value can satisfy the predicate and still
+    /// fail to convert (e.g. a Guile char outside the Unicode scalar value range), so that case can
+    /// be reported as [ConversionError::OutOfRange] instead.
+    fn try_from_scm_checked(scm: Scm<'gm>, guile: &'gm Guile) -> Result<Self, ConversionError>
+    where
+        Self: Sized,
+    {
+        if Self::predicate(&scm, guile) {
+            Ok(unsafe { Self::from_scm_unchecked(scm, guile) })
+        } else {
+            Err(ConversionError::WrongType)
+        }
+    }
+
     /// Create [Self] without type checking.
     ///
     /// # Safety
@@ -183,6 +213,71 @@ impl<'gm> Scm<'gm> {
             _marker: PhantomData,
         }
     }
+
+    /// Check whether this object can be downcast to `T`, mirroring [`Any::is`][std::any::Any::is].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{scm::{Scm, ToScm}, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let scm = 1.to_scm(guile);
+    ///     assert!(scm.is::<i32>(guile));
+    ///     assert!(!scm.is::<bool>(guile));
+    /// }).unwrap();
+    /// ```
+    pub fn is<T>(&self, guile: &'gm Guile) -> bool
+    where
+        T: TryFromScm<'gm>,
+    {
+        T::predicate(self, guile)
+    }
+
+    /// Attempt to downcast into a concrete type, mirroring [`Any::downcast`][std::any::Any::downcast].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{scm::{Scm, ToScm}, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let scm = 1.to_scm(guile);
+    ///     assert_eq!(scm.downcast::<i32>(guile), Ok(1));
+    /// }).unwrap();
+    /// ```
+    pub fn downcast<T>(self, guile: &'gm Guile) -> Result<T, Self>
+    where
+        T: TryFromScm<'gm>,
+    {
+        T::try_from_scm(self, guile)
+    }
+
+    /// Borrow this object as a concrete [ReprScm] type without consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gargoyle::{collections::pair::Pair, scm::{Scm, ToScm}, symbol::Symbol, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     let scm = Pair::new(1, 2, guile).to_scm(guile);
+    ///     assert!(scm.downcast_ref::<Pair<i32, i32>>(guile).is_some());
+    ///     assert!(scm.downcast_ref::<Symbol>(guile).is_none());
+    /// }).unwrap();
+    /// ```
+    pub fn downcast_ref<T>(&self, guile: &'gm Guile) -> Option<&T>
+    where
+        T: ReprScm + TryFromScm<'gm>,
+    {
+        if T::predicate(self, guile) {
+            // SAFETY: `T: ReprScm` guarantees layout compatibility with `SCM`, and `Scm` is
+            // itself `repr(transparent)` over the same pointer.
+            Some(unsafe { mem::transmute::<&Self, &T>(self) })
+        } else {
+            None
+        }
+    }
 }
 impl PartialEq for Scm<'_> {
     /// Compare equality with `equal?`
@@ -192,8 +287,8 @@ impl PartialEq for Scm<'_> {
 }
 unsafe impl ReprScm for Scm<'_> {}
 impl<'gm> TryFromScm<'gm> for Scm<'gm> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"any")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"any")
     }
 
     fn predicate(_: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -214,7 +309,7 @@ impl<'gm, T> TryFromScm<'gm> for Option<T>
 where
     T: TryFromScm<'gm>,
 {
-    fn type_name() -> Cow<'static, CStr> {
+    fn type_name() -> TypeName {
         T::type_name()
     }
 
@@ -28,13 +28,83 @@ use {
             scm_string, scm_string_equal_p, scm_string_null_p, scm_symbol_to_string,
             scm_to_utf8_stringn,
         },
+        type_name::TypeName,
         utils::{c_predicate, scm_predicate},
     },
     allocator_api2::vec::Vec,
-    std::{borrow::Cow, ffi::CStr, marker::PhantomData},
+    std::{fmt, marker::PhantomData, ops::Deref},
     string::String as BufString,
 };
 
+/// The largest content [SmallString::Inline] can hold; mirrors
+/// [`TypeName`][crate::type_name::TypeName]'s inline/heap split, sized so the inline payload
+/// (23 content bytes plus the length byte) takes the same 24 bytes as [SmallString::Heap]'s buffer.
+const SMALL_STRING_INLINE_CAPACITY: usize = 23;
+
+/// A UTF-8 string that stores short content (symbols, keywords, identifiers — the overwhelming
+/// majority of strings real Scheme code produces) inline rather than behind a malloc'd buffer.
+///
+/// Returned by [`String::as_small_string`] in place of [`String::as_string`]'s unconditional heap
+/// allocation. Longer content still falls back to [SmallString::Heap], so this is a drop-in: both
+/// variants [Deref] to `str`.
+pub enum SmallString {
+    /// `len` bytes of content, inline.
+    Inline {
+        buf: [u8; SMALL_STRING_INLINE_CAPACITY],
+        len: u8,
+    },
+    /// Content too long to fit inline, still held in the `malloc`'d buffer `scm_to_utf8_stringn`
+    /// produced.
+    Heap(BufString<Vec<u8, CAllocator>>),
+}
+impl Deref for SmallString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            // SAFETY: `String::as_small_string` only ever writes valid UTF-8 into `buf`.
+            Self::Inline { buf, len } => unsafe { str::from_utf8_unchecked(&buf[..*len as usize]) },
+            Self::Heap(string) => string,
+        }
+    }
+}
+impl AsRef<str> for SmallString {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+impl PartialEq<str> for SmallString {
+    fn eq(&self, other: &str) -> bool {
+        self.deref() == other
+    }
+}
+impl Clone for SmallString {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Inline { buf, len } => Self::Inline {
+                buf: *buf,
+                len: *len,
+            },
+            Self::Heap(string) => {
+                let mut buf = Vec::with_capacity_in(string.len(), CAllocator);
+                buf.extend_from_slice(string.as_bytes());
+                // SAFETY: copied verbatim from an already-validated `str`.
+                Self::Heap(unsafe { BufString::from_utf8_unchecked(buf) })
+            }
+        }
+    }
+}
+impl fmt::Display for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.deref(), f)
+    }
+}
+impl fmt::Debug for SmallString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.deref(), f)
+    }
+}
+
 /// Guile strings.
 #[derive(Debug)]
 #[repr(transparent)]
@@ -85,6 +155,50 @@ impl<'gm> String<'gm> {
         unsafe { BufString::from_utf8_unchecked(buffer) }
     }
 
+    /// Like [Self::as_string], but avoids the heap entirely for the short strings (symbols,
+    /// keywords, identifiers) that dominate real Scheme code: content of
+    /// [`SMALL_STRING_INLINE_CAPACITY`] bytes or fewer is copied inline into the returned
+    /// [SmallString] and the `malloc`'d buffer is freed immediately, instead of being retained.
+    ///
+    /// # Exceptions
+    ///
+    /// There may be exceptions if it fails to encode into utf8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use garguile::{string::String, with_guile};
+    /// # #[cfg(not(miri))]
+    /// with_guile(|guile| {
+    ///     assert_eq!(String::from_str("asdf", guile).as_small_string(), *"asdf");
+    /// }).unwrap();
+    /// ```
+    pub fn as_small_string(&self) -> SmallString {
+        let mut len = 0;
+        let ptr = unsafe { scm_to_utf8_stringn(self.scm.as_ptr(), &raw mut len) }.cast::<u8>();
+
+        // the documentation does not mention returning NULL.
+        assert!(!ptr.is_null());
+
+        // SAFETY: the string was allocated using `malloc`.
+        let buffer = unsafe { Vec::from_raw_parts_in(ptr, len, len, CAllocator) };
+
+        assert!(str::from_utf8(buffer.as_slice()).is_ok());
+
+        if len <= SMALL_STRING_INLINE_CAPACITY {
+            let mut buf = [0; SMALL_STRING_INLINE_CAPACITY];
+            buf[..len].copy_from_slice(&buffer);
+            // `buffer`'s `Drop` frees the `malloc`'d block right here, instead of retaining it.
+            SmallString::Inline {
+                buf,
+                len: len as u8,
+            }
+        } else {
+            // SAFETY: the returned string should be utf8, and we have an assertion above
+            SmallString::Heap(unsafe { BufString::from_utf8_unchecked(buffer) })
+        }
+    }
+
     /// Get the length of a string.
     ///
     /// # Examples
@@ -152,8 +266,8 @@ impl<'gm> ToScm<'gm> for String<'gm> {
     }
 }
 impl<'gm> TryFromScm<'gm> for String<'gm> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"string")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"string")
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -227,4 +341,36 @@ mod tests {
         })
         .unwrap();
     }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn to_small_string_inline() {
+        with_guile(|guile| {
+            let small = String::from_str("asdf", guile).as_small_string();
+            assert_eq!(&*small, "asdf");
+            assert!(small == *"asdf");
+            assert!(matches!(small, SmallString::Inline { .. }));
+        })
+        .unwrap();
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn to_small_string_heap() {
+        with_guile(|guile| {
+            let long = "a".repeat(SMALL_STRING_INLINE_CAPACITY + 1);
+            let small = String::from_str(&long, guile).as_small_string();
+            assert_eq!(&*small, long.as_str());
+            assert!(matches!(small, SmallString::Heap(_)));
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn small_string_clone() {
+        let mut buf = [0; SMALL_STRING_INLINE_CAPACITY];
+        buf[..4].copy_from_slice(b"asdf");
+        let inline = SmallString::Inline { buf, len: 4 };
+        assert_eq!(&*inline.clone(), "asdf");
+    }
 }
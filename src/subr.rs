@@ -15,15 +15,13 @@
 
 //! Guile functions.
 
-use {
-    crate::{
-        Guile,
-        reference::ReprScm,
-        scm::{Scm, ToScm, TryFromScm},
-        sys::{scm_call_n, scm_procedure_p},
-        utils::scm_predicate,
-    },
-    std::{borrow::Cow, ffi::CStr},
+use crate::{
+    Guile,
+    reference::ReprScm,
+    scm::{Scm, ToScm, TryFromScm},
+    sys::{scm_call_n, scm_procedure_p},
+    type_name::TypeName,
+    utils::scm_predicate,
 };
 
 pub(crate) trait TupleExt<'gm, const ARITY: usize> {
@@ -69,6 +67,55 @@ macro_rules! impl_tuple_ext_for {
 }
 impl_tuple_ext_for!(A, B, C, D, E, F, G, H, I, J, K, L);
 
+/// Reconstructs the arguments a [`crate::hook::Hook::connect`]ed closure expects out of the
+/// `Scm`s Guile calls it with; the mirror of [TupleExt].
+pub(crate) trait HookConnectFn<'gm, const ARITY: usize> {
+    fn call_scm(&mut self, args: [Scm<'gm>; ARITY], guile: &'gm Guile);
+}
+macro_rules! impl_hook_connect_fn_for {
+    () => {
+        impl<'gm, Func> $crate::subr::HookConnectFn<'gm, 0> for Func
+        where
+            Func: FnMut(),
+        {
+            fn call_scm(&mut self, _: [$crate::scm::Scm<'gm>; 0], _: &'gm $crate::Guile) {
+                self();
+            }
+        }
+    };
+    ($car:ident $(, $($cdr:ident),+)?) => {
+        impl<'gm, Func, $car $(, $($cdr),+)?> $crate::subr::HookConnectFn<'gm, {
+            1 $($(+ {
+                const $cdr: ::std::primitive::usize = 1;
+                $cdr
+            })+)?
+        }> for Func
+        where
+            Func: FnMut($car, $($($cdr),+)?),
+            $car: $crate::scm::TryFromScm<'gm>,
+            $($($cdr: $crate::scm::TryFromScm<'gm>),+)?
+        {
+            fn call_scm(&mut self, args: [$crate::scm::Scm<'gm>; {
+                1 $($(+ {
+                    const $cdr: ::std::primitive::usize = 1;
+                    $cdr
+                })+)?
+            }], guile: &'gm $crate::Guile) {
+                #[expect(non_snake_case)]
+                let [$car, $($($cdr),+)?] = args;
+
+                self(
+                    $crate::scm::TryFromScm::from_scm_or_throw($car, c"hook-connect-callback", 0, guile),
+                    $($($crate::scm::TryFromScm::from_scm_or_throw($cdr, c"hook-connect-callback", 0, guile)),+)?
+                );
+            }
+        }
+
+        impl_hook_connect_fn_for!($($($cdr),+)?);
+    };
+}
+impl_hook_connect_fn_for!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 /// Scheme functions.
 #[repr(transparent)]
 pub struct Proc<'gm>(Scm<'gm>);
@@ -106,8 +153,8 @@ impl<'gm> Proc<'gm> {
 }
 unsafe impl ReprScm for Proc<'_> {}
 impl<'gm> TryFromScm<'gm> for Proc<'gm> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"procedure")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"procedure")
     }
 
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
@@ -143,6 +190,7 @@ pub trait GuileFn {
 /// | `guile_ident` | Identifier of the function used in metadata. Defaults to the name of the function but in kebab case | [c string literal][CStr] |
 /// | `struct_ident` | The identifier used to implement [GuileFn]. Defaults to the name of the function but in pascal case | identfier |
 /// | `garguile_root` | The path to the `garguile` crate. This is useful if you renamed the crate. | path |
+/// | `rename` | The casing used to derive `guile_ident` when it isn't set explicitly. Defaults to `"kebab"`. One of `"kebab"`, `"snake"`, `"camel"`, `"pascal"`, `"screaming-snake"`, `"screaming-kebab"`, `"title"`, `"train"`, `"flat"`, `"upper-flat"`, `"upper"`, `"lower"`. | string literal |
 ///
 /// # Examples
 ///
@@ -210,6 +258,20 @@ pub trait GuileFn {
 /// ```
 /// # use garguile::{collections::list::List, module::Module, string::String, subr::{GuileFn, guile_fn}, symbol::Symbol, with_guile};
 /// #[guile_fn]
+/// fn increment(n: &i32, #[optional = 1] step: &i32) -> i32 {
+///     *n + *step
+/// }
+/// # #[cfg(not(miri))]
+/// with_guile(|guile| {
+///     Module::current(guile).define(Symbol::from_str("increment", guile), Increment::create(guile));
+///     assert_eq!(unsafe { guile.eval::<i32>(&String::from_str("(increment 5 10)", guile)) }, Ok(15));
+///     assert_eq!(unsafe { guile.eval::<i32>(&String::from_str("(increment 5)", guile)) }, Ok(6));
+/// }).unwrap();
+/// ```
+///
+/// ```
+/// # use garguile::{collections::list::List, module::Module, string::String, subr::{GuileFn, guile_fn}, symbol::Symbol, with_guile};
+/// #[guile_fn]
 /// fn area(#[keyword] width: Option<&i32>, height: Option<&i32>) -> i32 {
 ///     width.and_then(|width| height.map(|height| *width * *height)).unwrap_or_default()
 /// }
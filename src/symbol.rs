@@ -23,6 +23,7 @@
 use {
     crate::{
         Guile,
+        gc::Gc,
         reference::ReprScm,
         scm::{Scm, ToScm, TryFromScm},
         string::String,
@@ -30,9 +31,10 @@ use {
             SCM, scm_c_symbol_length, scm_from_utf8_symbol, scm_from_utf8_symboln, scm_make_symbol,
             scm_string_to_symbol, scm_symbol_interned_p, scm_symbol_p,
         },
+        type_name::TypeName,
         utils::scm_predicate,
     },
-    std::{borrow::Cow, ffi::CStr, marker::PhantomData},
+    std::{cell::RefCell, collections::HashMap, marker::PhantomData, mem},
 };
 
 /// Hashed strings
@@ -153,8 +155,8 @@ impl<'gm> ToScm<'gm> for Symbol<'gm> {
     }
 }
 impl<'gm> TryFromScm<'gm> for Symbol<'gm> {
-    fn type_name() -> Cow<'static, CStr> {
-        Cow::Borrowed(c"symbol")
+    fn type_name() -> TypeName {
+        TypeName::from_static(c"symbol")
     }
     fn predicate(scm: &Scm<'gm>, _: &'gm Guile) -> bool {
         scm_predicate(unsafe { scm_symbol_p(scm.as_ptr()) })
@@ -166,3 +168,66 @@ impl<'gm> TryFromScm<'gm> for Symbol<'gm> {
         }
     }
 }
+
+thread_local! {
+    /// Previously interned symbols, keyed by their Rust string.
+    ///
+    /// Keyed per-thread because guile mode itself is thread-scoped; each entry is kept alive by
+    /// [Gc] (its `'static` alias is never actually dereferenced past the `'gm` that produced it,
+    /// only re-borrowed with a fresh one, exactly as [`Gc::borrow`] does). This never shrinks:
+    /// it's bounded by the program's distinct symbol vocabulary, which for real call sites
+    /// (record field names, keyword arguments, ...) is small and fixed.
+    static INTERNED: RefCell<HashMap<std::string::String, Gc<Symbol<'static>>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Cache `Symbol::from_str` lookups in a thread-local map, so a fixed vocabulary of names pays
+/// Guile's FFI crossing plus its own interning lookup only once per name per thread, instead of
+/// on every call.
+///
+/// # Examples
+///
+/// ```
+/// # use garguile::{symbol::intern, with_guile};
+/// # #[cfg(not(miri))]
+/// with_guile(|guile| {
+///     assert_eq!(intern("foo", guile), intern("foo", guile));
+/// }).unwrap();
+/// ```
+pub fn intern<'gm>(name: &str, guile: &'gm Guile) -> Symbol<'gm> {
+    INTERNED.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some(symbol) = cache.get(name) {
+            return *symbol.borrow(guile);
+        }
+
+        let symbol = Symbol::from_str(name, guile);
+        // SAFETY: `Symbol<'gm>`/`Symbol<'static>` share the same representation (a bare `SCM`
+        // plus a zero-sized marker); only ever reborrowed through `Gc::borrow` with a live `'gm`
+        // afterwards, never read back as truly `'static`.
+        let protected = Gc::new(
+            unsafe { mem::transmute::<Symbol<'gm>, Symbol<'static>>(symbol) },
+            guile,
+        );
+        cache.insert(name.to_owned(), protected);
+        symbol
+    })
+}
+
+/// Ergonomic call-site wrapper for [`intern`]: `intern!(guile, "name")`.
+///
+/// # Examples
+///
+/// ```
+/// # use garguile::{intern, with_guile};
+/// # #[cfg(not(miri))]
+/// with_guile(|guile| {
+///     assert_eq!(intern!(guile, "foo"), intern!(guile, "foo"));
+/// }).unwrap();
+/// ```
+#[macro_export]
+macro_rules! intern {
+    ($guile:expr, $name:expr) => {
+        $crate::symbol::intern($name, $guile)
+    };
+}
@@ -39,9 +39,9 @@ pub type scm_t_catch_handler = Option<unsafe extern "C" fn(*mut c_void, SCM, SCM
 #[derive(Default)]
 #[repr(C)]
 pub struct scm_t_array_dim {
-    lbnd: isize,
-    ubnd: isize,
-    inc: isize,
+    pub lbnd: isize,
+    pub ubnd: isize,
+    pub inc: isize,
 }
 #[derive(Default)]
 #[repr(C)]
@@ -105,6 +105,10 @@ unsafe extern "C" {
     ) -> *mut c_void;
     pub fn scm_shell(_argc: c_int, _argv: *const *const c_char);
 
+    /// Registers the calling thread with Guile's GC for the rest of its life; unlike
+    /// [scm_with_guile], there is no matching "leave" call.
+    pub fn scm_init_guile();
+
     pub fn scm_from_utf8_stringn(_: *const c_char, _: usize) -> SCM;
     pub fn scm_to_utf8_stringn(_: SCM, _: *mut usize) -> *mut c_char;
 
@@ -121,6 +125,17 @@ unsafe extern "C" {
     pub fn scm_char_set_cursor(_cs: SCM) -> SCM;
     pub fn scm_char_set_cursor_next(_cs: SCM, _cursor: SCM) -> SCM;
 
+    pub fn scm_char_set_union(_rest: SCM) -> SCM;
+    pub fn scm_char_set_intersection(_rest: SCM) -> SCM;
+    pub fn scm_char_set_difference(_cs1: SCM, _rest: SCM) -> SCM;
+    pub fn scm_char_set_complement(_cs: SCM) -> SCM;
+    pub fn scm_char_set_adjoin(_cs: SCM, _rest: SCM) -> SCM;
+    pub fn scm_char_set_delete(_cs: SCM, _rest: SCM) -> SCM;
+    pub fn scm_char_set_size(_cs: SCM) -> SCM;
+    pub fn scm_char_set_eq(_rest: SCM) -> SCM;
+    pub fn scm_char_set_leq_p(_rest: SCM) -> SCM;
+    pub fn scm_char_set_filter(_pred: SCM, _domain: SCM, _base_cs: SCM) -> SCM;
+
     pub fn scm_end_of_char_set_p(_cursor: SCM) -> SCM;
 
     pub fn scm_from_double(_: c_double) -> SCM;
@@ -150,11 +165,24 @@ unsafe extern "C" {
     pub fn scm_car(_pair: SCM) -> SCM;
     pub fn scm_cdr(_pair: SCM) -> SCM;
     pub fn scm_cons(_x: SCM, _y: SCM) -> SCM;
+    pub fn scm_set_car_x(_pair: SCM, _val: SCM);
+    pub fn scm_set_cdr_x(_pair: SCM, _val: SCM);
     pub fn scm_length(_lst: SCM) -> SCM;
+    pub fn scm_reverse(_lst: SCM) -> SCM;
 
     pub fn scm_list_p(_x: SCM) -> SCM;
     pub fn scm_null_p(_x: SCM) -> SCM;
 
+    pub fn scm_caar(_pair: SCM) -> SCM;
+    pub fn scm_cdar(_pair: SCM) -> SCM;
+    pub fn scm_acons(_key: SCM, _val: SCM, _alist: SCM) -> SCM;
+    pub fn scm_assq(_key: SCM, _alist: SCM) -> SCM;
+    pub fn scm_assv(_key: SCM, _alist: SCM) -> SCM;
+    pub fn scm_assoc(_key: SCM, _alist: SCM) -> SCM;
+    pub fn scm_del_assq_x(_key: SCM, _alist: SCM) -> SCM;
+    pub fn scm_del_assv_x(_key: SCM, _alist: SCM) -> SCM;
+    pub fn scm_del_assoc_x(_key: SCM, _alist: SCM) -> SCM;
+
     pub fn scm_list_to_char_set(_list: SCM, _base_cs: SCM) -> SCM;
 
     pub fn scm_vector_p(_obj: SCM) -> SCM;
@@ -172,6 +200,7 @@ unsafe extern "C" {
     pub fn scm_c64vector_p(_obj: SCM) -> SCM;
 
     pub fn scm_vector(_l: SCM) -> SCM;
+    pub fn scm_c_vector_set_x(_vec: SCM, _k: usize, _obj: SCM);
     pub fn scm_list_to_u8vector(_lst: SCM) -> SCM;
     pub fn scm_list_to_s8vector(_lst: SCM) -> SCM;
     pub fn scm_list_to_u16vector(_lst: SCM) -> SCM;
@@ -359,6 +388,31 @@ unsafe extern "C" {
 
     pub fn scm_array_handle_release(_handle: *mut scm_t_array_handle);
 
+    pub fn scm_array_get_handle(_array: SCM, _handle: *mut scm_t_array_handle);
+    pub fn scm_array_handle_rank(_handle: *mut scm_t_array_handle) -> usize;
+    pub fn scm_array_handle_dims(_handle: *mut scm_t_array_handle) -> *mut scm_t_array_dim;
+    pub fn scm_array_handle_elements(_handle: *mut scm_t_array_handle) -> *const SCM;
+    pub fn scm_array_handle_writable_elements(_handle: *mut scm_t_array_handle) -> *mut SCM;
+    pub fn scm_array_p(_obj: SCM, _rank: SCM) -> SCM;
+
+    pub fn scm_bytevector_p(_obj: SCM) -> SCM;
+    pub fn scm_c_make_bytevector(_len: usize) -> SCM;
+    pub fn scm_c_bytevector_length(_bv: SCM) -> usize;
+    pub fn scm_bytevector_contents(_bv: SCM) -> *mut u8;
+
+    pub fn scm_c_make_u8vector(_len: usize) -> SCM;
+    pub fn scm_c_make_s8vector(_len: usize) -> SCM;
+    pub fn scm_c_make_u16vector(_len: usize) -> SCM;
+    pub fn scm_c_make_s16vector(_len: usize) -> SCM;
+    pub fn scm_c_make_u32vector(_len: usize) -> SCM;
+    pub fn scm_c_make_s32vector(_len: usize) -> SCM;
+    pub fn scm_c_make_u64vector(_len: usize) -> SCM;
+    pub fn scm_c_make_s64vector(_len: usize) -> SCM;
+    pub fn scm_c_make_f32vector(_len: usize) -> SCM;
+    pub fn scm_c_make_f64vector(_len: usize) -> SCM;
+    pub fn scm_c_make_c32vector(_len: usize) -> SCM;
+    pub fn scm_c_make_c64vector(_len: usize) -> SCM;
+
     pub fn scm_is_signed_integer(_: SCM, _: isize, _: isize) -> bool;
     pub fn scm_is_unsigned_integer(_: SCM, _: usize, _: usize) -> bool;
 
@@ -375,11 +429,20 @@ unsafe extern "C" {
     pub fn scm_exact_to_inexact(_z: SCM) -> SCM;
     pub fn scm_inexact_to_exact(_z: SCM) -> SCM;
 
+    pub fn scm_number_to_string(_n: SCM, _radix: SCM) -> SCM;
+    pub fn scm_string_to_number(_string: SCM, _radix: SCM) -> SCM;
+
     pub fn scm_sum(_z1: SCM, _z2: SCM) -> SCM;
     pub fn scm_difference(_z1: SCM, _z2: SCM) -> SCM;
     pub fn scm_divide(_z1: SCM, _z2: SCM) -> SCM;
     pub fn scm_remainder(_n: SCM, _d: SCM) -> SCM;
+    pub fn scm_modulo(_n: SCM, _d: SCM) -> SCM;
+    pub fn scm_modulo_expt(_n: SCM, _k: SCM, _m: SCM) -> SCM;
+    pub fn scm_euclidean_divide(_x: SCM, _y: SCM, _qp: *mut SCM, _rp: *mut SCM);
     pub fn scm_product(_z1: SCM, _z2: SCM) -> SCM;
+    pub fn scm_expt(_z1: SCM, _z2: SCM) -> SCM;
+    pub fn scm_sqrt(_z: SCM) -> SCM;
+    pub fn scm_abs(_z: SCM) -> SCM;
 
     pub fn scm_logand(_n1: SCM, _n2: SCM) -> SCM;
     pub fn scm_logior(_n1: SCM, _n2: SCM) -> SCM;
@@ -397,6 +460,23 @@ unsafe extern "C" {
 
     pub fn scm_gc_protect_object(_: SCM) -> SCM;
     pub fn scm_gc_unprotect_object(_: SCM) -> SCM;
+    pub fn scm_gc_malloc(_size: usize, _what: *const c_char) -> *mut c_void;
+    /// Force an immediate full garbage collection; what `(gc)` calls in Scheme.
+    pub fn scm_gc() -> SCM;
+
+    /// Register a finalizer with the BDW garbage collector underlying guile's heap.
+    ///
+    /// Matches `GC_finalization_proc fn(void *obj, void *client_data)`; `ofn`/`ocd` receive any
+    /// previously registered finalizer for `obj`, which callers here always pass as null since
+    /// each block is only ever finalized once.
+    #[allow(non_snake_case)]
+    pub fn GC_register_finalizer_no_order(
+        _obj: *mut c_void,
+        _fn: Option<unsafe extern "C" fn(_obj: *mut c_void, _client_data: *mut c_void)>,
+        _cd: *mut c_void,
+        _ofn: *mut Option<unsafe extern "C" fn(_obj: *mut c_void, _client_data: *mut c_void)>,
+        _ocd: *mut *mut c_void,
+    );
 
     pub fn scm_eq_p(_: SCM, _: SCM) -> SCM;
     pub fn scm_eqv_p(_: SCM, _: SCM) -> SCM;
@@ -442,6 +522,11 @@ unsafe extern "C" {
         _: *mut c_void,
         _: scm_t_wind_flags,
     );
+    pub fn scm_dynwind_rewind_handler(
+        _: Option<unsafe extern "C" fn(_: *mut c_void)>,
+        _: *mut c_void,
+        _: scm_t_wind_flags,
+    );
     pub fn scm_dynwind_end();
 
     pub fn scm_internal_catch(
@@ -451,6 +536,30 @@ unsafe extern "C" {
         _handler: scm_t_catch_handler,
         _handler_data: *mut c_void,
     ) -> SCM;
+
+    pub fn scm_make_fluid_with_default(_dflt: SCM) -> SCM;
+    pub fn scm_fluid_ref(_fluid: SCM) -> SCM;
+    pub fn scm_fluid_set_x(_fluid: SCM, _value: SCM) -> SCM;
+    pub fn scm_c_with_fluid(
+        _fluid: SCM,
+        _value: SCM,
+        _cproc: scm_t_thunk,
+        _cdata: *mut c_void,
+    ) -> SCM;
+
+    pub fn scm_hash_create_handle_x(_table: SCM, _key: SCM, _init: SCM) -> SCM;
+    pub fn scm_hashq_create_handle_x(_table: SCM, _key: SCM, _init: SCM) -> SCM;
+    pub fn scm_hashv_create_handle_x(_table: SCM, _key: SCM, _init: SCM) -> SCM;
+
+    pub fn scm_hash_clear_x(_table: SCM) -> SCM;
+    pub fn scm_hash_fold(_proc: SCM, _init: SCM, _table: SCM) -> SCM;
+
+    pub fn scm_random(_m: SCM, _state: SCM) -> SCM;
+    pub fn scm_copy_random_state(_state: SCM) -> SCM;
+    pub fn scm_seed_to_random_state(_seed: SCM) -> SCM;
+
+    pub fn scm_remove_hook_x(_hook: SCM, _proc: SCM) -> SCM;
+    pub fn scm_hook_to_list(_hook: SCM) -> SCM;
 }
 
 pub use GARGOYLE_REEXPORTS_SCM_BOOL_F as SCM_BOOL_F;
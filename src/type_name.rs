@@ -0,0 +1,178 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Allocation-free storage for [`TryFromScm::type_name`][crate::scm::TryFromScm::type_name].
+
+use std::{
+    collections::HashSet,
+    ffi::{CStr, CString},
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+/// The largest content a [TypeName::Inline] can hold, leaving one byte for the NUL terminator.
+const INLINE_CAPACITY: usize = 21;
+
+/// A NUL-terminated type name, as returned by [`TryFromScm::type_name`][crate::scm::TryFromScm::type_name].
+///
+/// Most type names (`integer`, `(integer . integer)`, `'(integer integer)`) are short enough to
+/// live inline on the stack; only deeply nested composites spill onto the heap, and even then the
+/// [Arc] keeps further [Clone]s cheap.
+#[derive(Clone)]
+pub enum TypeName {
+    /// Content plus a NUL terminator packed into a fixed-size buffer; `len` is the content length,
+    /// not counting the terminator.
+    Inline {
+        buf: [u8; INLINE_CAPACITY + 1],
+        len: u8,
+    },
+    /// Content that didn't fit inline.
+    Shared(Arc<CStr>),
+}
+impl TypeName {
+    /// Build a [TypeName] from a single, already NUL-terminated name, without going through
+    /// [TypeNameBuilder].
+    pub fn from_static(name: &'static CStr) -> Self {
+        let bytes = name.to_bytes();
+        match bytes.len() {
+            len @ ..=INLINE_CAPACITY => {
+                let mut buf = [0; INLINE_CAPACITY + 1];
+                buf[..len].copy_from_slice(bytes);
+                Self::Inline {
+                    buf,
+                    len: len as u8,
+                }
+            }
+            _ => Self::Shared(intern(name.to_owned().into_boxed_c_str())),
+        }
+    }
+}
+impl Deref for TypeName {
+    type Target = CStr;
+
+    fn deref(&self) -> &CStr {
+        match self {
+            Self::Inline { buf, len } => {
+                // SAFETY: `finish`/`from_static` only ever write a single interior NUL, right
+                // after `len` bytes of non-NUL content.
+                unsafe { CStr::from_bytes_with_nul_unchecked(&buf[..=*len as usize]) }
+            }
+            Self::Shared(name) => name,
+        }
+    }
+}
+impl AsRef<CStr> for TypeName {
+    fn as_ref(&self) -> &CStr {
+        self
+    }
+}
+impl Debug for TypeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+impl PartialEq for TypeName {
+    /// Two [Shared][TypeName::Shared] names interned from the same [TypeNameBuilder] are the same
+    /// allocation, so [Arc::ptr_eq] lets the common case (comparing two recursively-built composite
+    /// names, e.g. while deduplicating "expected type X" diagnostics) skip the byte comparison
+    /// entirely; anything else still falls back to comparing the names themselves.
+    fn eq(&self, other: &Self) -> bool {
+        if let (Self::Shared(this), Self::Shared(other)) = (self, other)
+            && Arc::ptr_eq(this, other)
+        {
+            true
+        } else {
+            **self == **other
+        }
+    }
+}
+impl Eq for TypeName {}
+
+/// Return the canonical, process-wide-unique [Arc] for `name`'s contents, so that two
+/// [TypeName::Shared]s built from equal bytes always share one allocation and can be compared by
+/// pointer. Only [TypeNameBuilder::finish] and [TypeName::from_static] call this, and only for
+/// content too long to fit inline: it's just not worth interning the
+/// [TypeName::Inline] case, since those are already cheap to compare and cheap to copy.
+fn intern(name: Box<CStr>) -> Arc<CStr> {
+    static TABLE: OnceLock<RwLock<HashSet<Arc<CStr>>>> = OnceLock::new();
+    let table = TABLE.get_or_init(|| RwLock::new(HashSet::new()));
+
+    if let Some(existing) = table.read().unwrap().get(name.as_ref()) {
+        return Arc::clone(existing);
+    }
+    let mut table = table.write().unwrap();
+    if let Some(existing) = table.get(name.as_ref()) {
+        return Arc::clone(existing);
+    }
+    let name = Arc::from(name);
+    table.insert(Arc::clone(&name));
+    name
+}
+
+/// Incrementally builds a [TypeName] out of byte slices, only spilling onto the heap once the
+/// accumulated content exceeds [TypeName]'s inline capacity — so the recursive concatenation
+/// composite types need (e.g. `Pair`'s `"({} . {})"`) stays allocation-free for the common case
+/// instead of paying for a fresh [format!] on every call.
+pub struct TypeNameBuilder {
+    buf: [u8; INLINE_CAPACITY + 1],
+    len: usize,
+    spilled: Option<Vec<u8>>,
+}
+impl TypeNameBuilder {
+    pub fn new() -> Self {
+        Self {
+            buf: [0; INLINE_CAPACITY + 1],
+            len: 0,
+            spilled: None,
+        }
+    }
+
+    /// Append `bytes`, which must not contain an interior NUL.
+    pub fn push(&mut self, bytes: &[u8]) -> &mut Self {
+        match &mut self.spilled {
+            Some(spilled) => spilled.extend_from_slice(bytes),
+            None if self.len + bytes.len() <= INLINE_CAPACITY => {
+                self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+                self.len += bytes.len();
+            }
+            None => {
+                let mut spilled = self.buf[..self.len].to_vec();
+                spilled.extend_from_slice(bytes);
+                self.spilled = Some(spilled);
+            }
+        }
+        self
+    }
+
+    pub fn finish(self) -> TypeName {
+        match self.spilled {
+            Some(spilled) => TypeName::Shared(intern(
+                CString::new(spilled)
+                    .expect("type names must not contain an interior NUL")
+                    .into_boxed_c_str(),
+            )),
+            None => TypeName::Inline {
+                buf: self.buf,
+                len: self.len as u8,
+            },
+        }
+    }
+}
+impl Default for TypeNameBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -13,15 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use {
-    crate::sys::{SCM, scm_is_true},
-    bstr::BStr,
-    std::{
-        borrow::Cow,
-        ffi::{CStr, c_int},
-        fmt::{self, Display, Formatter},
-    },
-};
+use crate::sys::{SCM, scm_is_true};
+use std::ffi::c_int;
 
 pub fn c_predicate(b: c_int) -> bool {
     b != 0
@@ -30,18 +23,3 @@ pub fn c_predicate(b: c_int) -> bool {
 pub fn scm_predicate(b: SCM) -> bool {
     c_predicate(unsafe { scm_is_true(b) })
 }
-
-pub trait CowCStrExt<'a> {
-    fn display(&'a self) -> CowCStrDisplay<'a>;
-}
-impl<'a> CowCStrExt<'a> for Cow<'a, CStr> {
-    fn display(&'a self) -> CowCStrDisplay<'a> {
-        CowCStrDisplay(self)
-    }
-}
-pub struct CowCStrDisplay<'a>(&'a Cow<'a, CStr>);
-impl<'a> Display for CowCStrDisplay<'a> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
-        BStr::new(self.0.as_ref().to_bytes()).fmt(f)
-    }
-}
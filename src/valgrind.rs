@@ -0,0 +1,54 @@
+// garguile - guile bindings for rust
+// Copyright (C) 2025  Andrew Chi
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional [Valgrind memcheck](https://valgrind.org/docs/manual/mc-manual.html) annotations for
+//! the array-handle-backed pointers used throughout [collections][crate::collections].
+//!
+//! Both functions here are true no-ops unless the `valgrind` feature is enabled, and even then
+//! the underlying client-request protocol is itself a no-op when the process isn't running
+//! under Valgrind, so calling them has no effect on release behavior.
+
+use std::ffi::c_void;
+
+#[cfg(feature = "valgrind")]
+unsafe extern "C" {
+    fn garguile_valgrind_make_mem_defined(_addr: *const c_void, _len: usize) -> i64;
+    fn garguile_valgrind_make_mem_noaccess(_addr: *const c_void, _len: usize) -> i64;
+}
+
+/// Tell memcheck that the `len` bytes at `ptr` are defined, so reading through a freshly opened
+/// array handle doesn't trip a false "uninitialized value" report.
+#[cfg(feature = "valgrind")]
+pub(crate) fn make_mem_defined(ptr: *const c_void, len: usize) {
+    unsafe {
+        garguile_valgrind_make_mem_defined(ptr, len);
+    }
+}
+/// See [make_mem_defined].
+#[cfg(not(feature = "valgrind"))]
+pub(crate) fn make_mem_defined(_ptr: *const c_void, _len: usize) {}
+
+/// Tell memcheck that the `len` bytes at `ptr` are no longer accessible, so a stale read after
+/// [`scm_array_handle_release`][crate::sys::scm_array_handle_release] is reported as an invalid
+/// access instead of passing silently.
+#[cfg(feature = "valgrind")]
+pub(crate) fn make_mem_noaccess(ptr: *const c_void, len: usize) {
+    unsafe {
+        garguile_valgrind_make_mem_noaccess(ptr, len);
+    }
+}
+/// See [make_mem_noaccess].
+#[cfg(not(feature = "valgrind"))]
+pub(crate) fn make_mem_noaccess(_ptr: *const c_void, _len: usize) {}